@@ -23,6 +23,7 @@ var components: Array;
 var last_id: float = -1.0;
 var edit_index: int = -1;
 var component_name: String = "";
+var component_version: int = 0;
 var inspector: EditorInspector;
 var inspector_object: Object;
 var modifying_node: Node;
@@ -79,8 +80,9 @@ func on_open(
 		left.visible = false;
 		setup_inspector(null, {});
 		for c in components:
-			if component_name == c.get("name"):
-				setup_inspector(c, old_data.get("data"));
+			if component_name == c.get("name") || c.get("aliases", []).has(component_name):
+				self.component_name = c.get("name");
+				setup_inspector(c, migrate_data(c, old_data));
 				break;
 		return;
 
@@ -95,6 +97,37 @@ func on_open(
 		item.set_text(0, component.get("name"));
 		item.set_metadata(0, component);
 
+## Returns the fields of [param old_data] updated to the current fields of [param component],
+## and sets [member component_version] to the version they should be saved with.
+##
+## Fields saved under a previous name are moved to their current name. If [param old_data]
+## was saved with an older version, the component's migration runs as well and the current
+## version is used. Otherwise the saved version is kept.
+func migrate_data(component: Dictionary, old_data: Dictionary) -> Dictionary:
+	var data: Dictionary = old_data.get("data", {}).duplicate();
+	for field in component.get("fields", []):
+		var field_name: String = field.get("name");
+		if data.has(field_name):
+			continue;
+		for old_name in field.get("renamed_from", PackedStringArray()):
+			if data.has(old_name):
+				data[field_name] = data[old_name];
+				data.erase(old_name);
+				break;
+
+	component_version = old_data.get("version", 0);
+	var current_version: int = component.get("version", 0);
+	if component_version < current_version:
+		data = ClassDB.class_call_static(
+			"KissingRegistry",
+			"migrate_kissing_component_data",
+			component.get("name"),
+			component_version,
+			data
+		);
+		component_version = current_version;
+	return data;
+
 ## Removes all elements from [member component_list] execpt the [member root].
 func clear_component_list() -> void:
 	component_list.clear();
@@ -125,6 +158,7 @@ func emit_data() -> void:
 	if edit_index >= 0:
 		on_entry_edited.emit(edit_index, {
 			"name": component_name,
+			"version": component_version,
 			"data": generate_data()
 		});
 		return;
@@ -137,7 +171,8 @@ func emit_data() -> void:
 	if text.is_empty():
 		return;
 
-	on_entry_added.emit({ "name": text, "data": generate_data() });
+	var version = selected.get_metadata(0).get("version", 0);
+	on_entry_added.emit({ "name": text, "version": version, "data": generate_data() });
 
 ## Called when an item in [member component_list] is selected.
 ## Updates the properties listed on the right side.
@@ -150,6 +185,9 @@ func on_component_selected() -> void:
 
 	var data = selected.get_metadata(0);
 	description.text = data.get("docs");
+	var requires: PackedStringArray = data.get("requires", PackedStringArray());
+	if not requires.is_empty():
+		description.text += "\n\n[b]Requires:[/b] " + ", ".join(requires);
 	setup_inspector(data, {});
 
 ## Frees the [member inspector_object] if it exists and clears 
@@ -180,6 +218,9 @@ func setup_inspector(data: Variant, old_data: Dictionary) -> void:
 		return;
 
 	inspector_object = ClassDB.instantiate(data_class_name);
+	# Components registered by reflection share one class that needs the component's fields.
+	if inspector_object.has_method("setup_component"):
+		inspector_object.setup_component(data.get("name"));
 	inspector_object.set_meta("__base_node_relative", modifying_node);
 	for key in old_data.keys():
 		inspector_object.set(key, old_data[key]);
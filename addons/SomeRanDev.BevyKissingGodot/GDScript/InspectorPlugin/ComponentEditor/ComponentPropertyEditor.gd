@@ -5,13 +5,178 @@ class_name BKGComponentEditorProperty extends BKGEditorProperty
 
 const COMPONENT_DIALOG = preload("res://addons/SomeRanDev.BevyKissingGodot/Scenes/ComponentEditor/ComponentDialog.tscn");
 
+## The metadata that stores the overrides of the first level of instanced scenes.
+## Deeper levels add their level, like "bevy_component_overrides_2".
+const OVERRIDES_META_NAME := "bevy_component_overrides";
+
 # ---
 
 func get_dialog_scene() -> PackedScene:
 	return COMPONENT_DIALOG;
 
+## Nodes from an instanced scene store their components in "bevy_component_overrides",
+## so only the overridden fields are saved in this scene and the rest still come from
+## the instanced scene's "bevy_components".
+##
+## Each level of nesting has its own metadata, since an outer scene overriding the
+## metadata of an inner one would replace all of its overrides.
 func get_meta_storage_name() -> String:
-	return "bevy_components";
+	return get_overrides_meta_name(get_instance_level());
 
 func update_item_from_data(item: TreeItem, data: Dictionary) -> void:
-	item.set_text(0, data.get("name"));
+	if data.get("removed", false):
+		item.set_text(0, data.get("name") + " (removed)");
+		item.set_custom_color(0, get_theme_color("disabled_font_color", "Editor"));
+		item.set_tooltip_text(0, "Removed from this instance. Remove this entry to restore it.");
+	else:
+		item.set_text(0, data.get("name"));
+
+## Lists the components of the instanced scene that aren't overridden in this scene.
+func make_extra_items() -> void:
+	for entry in get_inherited_entries():
+		var item := make_item_from_data(entry);
+		item.set_text(0, entry.get("name") + " (inherited)");
+		item.set_custom_color(0, get_theme_color("readonly_font_color", "Editor"));
+		item.set_tooltip_text(0, "Added by the instanced scene. Edit it to override its fields, or remove it to remove it from this instance.");
+
+## Removing an inherited component stores a "removed" override, so the component is
+## removed from this instance only.
+func remove_entry(index: int) -> void:
+	var overrides := modifying_node.get_meta(get_meta_storage_name(), []) as Array;
+	if index < overrides.size():
+		super(index);
+		return;
+
+	var inherited := get_inherited_entries();
+	var inherited_index := index - overrides.size();
+	if inherited_index >= inherited.size():
+		return;
+
+	var entry: Dictionary = inherited[inherited_index];
+	overrides.push_back({
+		"name": entry.get("name"),
+		"version": entry.get("version", 0),
+		"data": {},
+		"removed": true,
+	});
+	modifying_node.set_meta(get_meta_storage_name(), overrides);
+
+## Only keeps the fields that differ from the instanced scene's component of the same name.
+func prepare_entry_data(data: Dictionary) -> Dictionary:
+	var base := find_base_data(data.get("name"));
+	if base.is_empty():
+		return data;
+
+	var base_fields: Dictionary = base.get("data", {});
+	var fields: Dictionary = data.get("data", {});
+	var overridden_fields := {};
+	for key in fields:
+		if !base_fields.has(key) || base_fields[key] != fields[key]:
+			overridden_fields[key] = fields[key];
+
+	var result := data.duplicate();
+	result["data"] = overridden_fields;
+	return result;
+
+## Merges the overridden fields on top of the instanced scene's fields, so the dialog
+## shows the values the component will actually have.
+func get_data_from_index(index: int) -> Dictionary:
+	var overrides := modifying_node.get_meta(get_meta_storage_name(), []) as Array;
+	if index >= overrides.size():
+		var inherited := get_inherited_entries();
+		var inherited_index := index - overrides.size();
+		return inherited[inherited_index] if inherited_index < inherited.size() else {};
+
+	var data := super(index);
+	var base := find_base_data(data.get("name", ""));
+	if base.is_empty():
+		return data;
+
+	var fields: Dictionary = base.get("data", {}).duplicate();
+	fields.merge(data.get("data", {}), true);
+
+	var result := data.duplicate();
+	result["data"] = fields;
+	return result;
+
+## Returns the name of the metadata that stores the components of a node nested in
+## [param level] instanced scenes.
+func get_overrides_meta_name(level: int) -> String:
+	if level <= 0:
+		return "bevy_components";
+	elif level == 1:
+		return OVERRIDES_META_NAME;
+	else:
+		return "%s_%d" % [OVERRIDES_META_NAME, level];
+
+## Returns how many instanced scenes [member modifying_node] is nested in, relative to
+## the edited scene. Returns [code]0[/code] if it's part of the edited scene.
+func get_instance_level() -> int:
+	if modifying_node == null || !Engine.is_editor_hint():
+		return 0;
+
+	var edited_scene_root := EditorInterface.get_edited_scene_root();
+	if modifying_node == edited_scene_root:
+		return 0;
+
+	# Nodes of an instanced scene are owned by its root, which is owned by the scene
+	# it's instanced in, and so on up to the edited scene.
+	var level := 0 if modifying_node.scene_file_path.is_empty() else 1;
+	var owner_node := modifying_node.owner;
+	while owner_node != null && owner_node != edited_scene_root:
+		level += 1;
+		owner_node = owner_node.owner;
+	return level;
+
+## Returns [code]true[/code] if [member modifying_node] is the root or a child of
+## an instanced scene.
+func is_instanced_node() -> bool:
+	return get_instance_level() > 0;
+
+## Returns the components of the instanced scene with the overrides of each inner level
+## merged on top, the same way they're merged when the components are added.
+func get_base_entries() -> Array:
+	var level := get_instance_level();
+	if level <= 0:
+		return [];
+
+	var entries := [];
+	for entry in modifying_node.get_meta("bevy_components", []):
+		entries.push_back(entry.duplicate(true));
+
+	for inner_level in range(1, level):
+		for override_entry in modifying_node.get_meta(get_overrides_meta_name(inner_level), []):
+			var index := find_entry_index(entries, override_entry.get("name"));
+			if override_entry.get("removed", false):
+				if index >= 0:
+					entries.remove_at(index);
+			elif index >= 0:
+				entries[index]["data"].merge(override_entry.get("data", {}), true);
+			else:
+				entries.push_back(override_entry.duplicate(true));
+	return entries;
+
+## Returns the components of the instanced scene that aren't overridden or removed in
+## this scene.
+func get_inherited_entries() -> Array:
+	var overrides := modifying_node.get_meta(get_meta_storage_name(), []) as Array;
+	var result := [];
+	for entry in get_base_entries():
+		if find_entry_index(overrides, entry.get("name")) < 0:
+			result.push_back(entry);
+	return result;
+
+## Returns the entry for the component named [param component_name] in the instanced
+## scene, or an empty [Dictionary] if there isn't one.
+func find_base_data(component_name: String) -> Dictionary:
+	var entries := get_base_entries();
+	var index := find_entry_index(entries, component_name);
+	return entries[index] if index >= 0 else {};
+
+## Returns the index of the entry named [param component_name] in [param entries], or
+## [code]-1[/code] if there isn't one.
+func find_entry_index(entries: Array, component_name: String) -> int:
+	for i in entries.size():
+		if entries[i].get("name") == component_name:
+			return i;
+	return -1;
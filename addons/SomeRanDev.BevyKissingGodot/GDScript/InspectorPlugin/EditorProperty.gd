@@ -31,6 +31,23 @@ func get_meta_storage_name() -> String;
 @abstract
 func update_item_from_data(item: TreeItem, data: Dictionary) -> void;
 
+## Called with the data from [member dialog] before it's stored in the metadata.
+func prepare_entry_data(data: Dictionary) -> Dictionary:
+	return data;
+
+## Called after the items of the metadata are made, to add items that aren't stored in it.
+## Their indexes come after the metadata's entries.
+func make_extra_items() -> void:
+	pass;
+
+## Removes the entry at [param index] of the metadata.
+func remove_entry(index: int) -> void:
+	var meta_name := get_meta_storage_name();
+	var bevy_event := modifying_node.get_meta(meta_name, []) as Array;
+	if index >= 0 && index < bevy_event.size():
+		bevy_event.remove_at(index);
+		modifying_node.set_meta(meta_name, bevy_event);
+
 # ---
 
 ## Called after initialization but before [func _ready].
@@ -52,12 +69,11 @@ func _ready() -> void:
 	component_list.item_activated.connect(on_component_list_activated);
 
 	if modifying_node != null:
-		var meta_name := get_meta_storage_name();
-		checked = modifying_node.has_meta(meta_name);
+		refresh_items();
 
-		var components = modifying_node.get_meta(meta_name, []);
-		for c in components:
-			make_item_from_data(c);
+		# Extra items, like the components of an instanced scene, are shown even if
+		# nothing is stored in this node's metadata yet.
+		checked = modifying_node.has_meta(get_meta_storage_name()) || root.get_child_count() > 0;
 
 	property_checked.connect(on_checked);
 	on_checked("", checked);
@@ -91,9 +107,20 @@ func ensure_dialog_exists() -> void:
 
 ## Generates a new [TreeItem] given [param name] and its corresponding
 ## component data.
-func make_item_from_data(data: Dictionary) -> void:
+func make_item_from_data(data: Dictionary) -> TreeItem:
 	var item := root.create_child();
 	update_item_from_data(item, data);
+	return item;
+
+## Rebuilds the items of [member component_list] from the metadata.
+func refresh_items() -> void:
+	for item in root.get_children():
+		root.remove_child(item);
+		item.free();
+
+	for c in modifying_node.get_meta(get_meta_storage_name(), []):
+		make_item_from_data(c);
+	make_extra_items();
 
 ## Returns a component's property values given its [param index] in the list of
 ## components in the [member modifying_node]'s "bevy_event" metadata.
@@ -120,13 +147,8 @@ func on_remove_clicked() -> void:
 	if item == null:
 		return;
 
-	var meta_name := get_meta_storage_name();
-	var index := item.get_index();
-	var bevy_event := modifying_node.get_meta(meta_name, []) as Array;
-	if index >= 0 && index < bevy_event.size():
-		bevy_event.remove_at(index);
-		root.remove_child(item);
-		item.free();
+	remove_entry(item.get_index());
+	refresh_items();
 
 ## Called when an item is double clicked so it may be edited.
 func on_component_list_activated() -> void:
@@ -145,21 +167,24 @@ func on_component_list_activated() -> void:
 
 ## Connected to [member dialog]'s [signal BKGAddDialog.on_component_added].
 func on_entry_added(new_data: Dictionary) -> void:
+	new_data = prepare_entry_data(new_data);
 	var meta_name := get_meta_storage_name();
 	var bevy_event := modifying_node.get_meta(meta_name, []) as Array;
 	bevy_event.push_back(new_data);
 	modifying_node.set_meta(meta_name, bevy_event);
 
-	make_item_from_data(new_data);
+	refresh_items();
 
 ## Connected to [member dialog]'s [signal BKGAddDialog.on_component_edited].
 func on_entry_edited(index: int, new_data: Dictionary) -> void:
+	new_data = prepare_entry_data(new_data);
 	var meta_name := get_meta_storage_name();
 	var bevy_event := modifying_node.get_meta(meta_name, []) as Array;
 	if index >= 0 && index < bevy_event.size():
 		bevy_event[index] = new_data;
-		modifying_node.set_meta(meta_name, bevy_event);
-		
-		var item = root.get_child(index);
-		if item:
-			update_item_from_data(item, new_data);
+	else:
+		# Extra items (see [method make_extra_items]) are stored once they're edited.
+		bevy_event.push_back(new_data);
+	modifying_node.set_meta(meta_name, bevy_event);
+
+	refresh_items();
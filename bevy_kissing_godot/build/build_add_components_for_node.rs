@@ -14,12 +14,13 @@ use serde_json::from_str as json_from_str;
 // ----------
 
 const OUTPUT_FILE_NAME: &str = "add_components_for_node.rs";
+const CLASS_INDICES_FILE_NAME: &str = "godot_class_indices.rs";
 
 // -------------
 // * Functions *
 // -------------
 
-/// Generates `OUT_DIR/add_components_for_node.rs`.
+/// Generates `OUT_DIR/add_components_for_node.rs` and `OUT_DIR/godot_class_indices.rs`.
 pub(crate) fn build_add_components_for_node() {
 	let out_dir = env::var_os("OUT_DIR").unwrap();
 	let extension_api_path = Path::new(&out_dir).join("extension_api.json");
@@ -30,6 +31,10 @@ pub(crate) fn build_add_components_for_node() {
 	println!("cargo:rerun-if-env-changed=GODOT4_BIN");
 	println!("cargo:rerun-if-env-changed=GODOT_PATH");
 
+	// With the `godot_class` feature, a single `GodotClass` is inserted instead of a
+	// `GodotNode<T>` for the class and each of its ancestors.
+	let use_godot_class = env::var_os("CARGO_FEATURE_GODOT_CLASS").is_some();
+
 	// ---
 	// Get Godot path and ensure extension_api.json exists
 
@@ -48,11 +53,22 @@ pub(crate) fn build_add_components_for_node() {
 	let inherit_map = generate_inherit_map(&extension_api_json);
 	let (class_name_list, class_inheritance_list_map) = generate_class_data(inherit_map);
 	let class_name_hashes = generate_class_name_hashes(&class_name_list, godot_path, &out_dir);
-	let entries = generate_cases(
-		class_name_list,
-		class_name_hashes,
-		class_inheritance_list_map,
-	);
+	if use_godot_class {
+		generate_class_indices_file(&out_dir, &class_name_list, &class_name_hashes);
+	}
+	let entries = if use_godot_class {
+		generate_godot_class_cases(
+			class_name_list,
+			class_name_hashes,
+			class_inheritance_list_map,
+		)
+	} else {
+		generate_cases(
+			class_name_list,
+			class_name_hashes,
+			class_inheritance_list_map,
+		)
+	};
 	generate_file(out_dir, entries);
 }
 
@@ -232,6 +248,74 @@ fn generate_cases(
 	entries
 }
 
+/// Generates a `String` list of code for the `match` branches when using the `godot_class`
+/// feature.
+///
+/// Each class is given the index of its position in `class_name_list`.
+fn generate_godot_class_cases(
+	class_name_list: Vec<&str>,
+	class_name_hashes: Vec<u64>,
+	class_inheritance_list_map: BTreeMap<&str, Vec<&str>>,
+) -> Vec<String> {
+	let class_indices = class_name_list
+		.iter()
+		.enumerate()
+		.map(|(index, name)| (*name, index))
+		.collect::<BTreeMap<&str, usize>>();
+
+	let mut entries: Vec<String> = vec![];
+	for i in 0..class_name_list.len() {
+		let name = class_name_list[i];
+		let Some(inherits) = class_inheritance_list_map.get(name) else {
+			continue;
+		};
+		let ancestry_indices = vec![name]
+			.into_iter()
+			.chain(inherits.iter().copied())
+			.filter_map(|cls| class_indices.get(cls))
+			.map(|index| index.to_string())
+			.collect::<Vec<String>>()
+			.join(", ");
+		entries.push(format!(
+			"\n\t\t{} => {{ entity_mut.insert(crate::components::godot_class::GodotClass::new({}, &[{}])); entity_mut }}",
+			class_name_hashes[i], class_name_hashes[i], ancestry_indices,
+		));
+	}
+	entries
+}
+
+/// Generates and writes the Rust code for `OUT_DIR/godot_class_indices.rs`.
+///
+/// This provides the number of engine classes and a function to get a class's index from
+/// its `StringName` hash, which are used by `GodotClass` to store its ancestry.
+fn generate_class_indices_file(
+	out_dir: &OsString,
+	class_name_list: &[&str],
+	class_name_hashes: &[u64],
+) {
+	let dest_path = Path::new(out_dir).join(CLASS_INDICES_FILE_NAME);
+	fs::write(
+		&dest_path,
+		format!(
+			"const GODOT_CLASS_COUNT: usize = {};
+
+fn engine_class_index(class_name_hash: u32) -> Option<usize> {{
+	match class_name_hash {{{}
+		_ => None,
+	}}
+}}",
+			class_name_list.len(),
+			class_name_hashes
+				.iter()
+				.enumerate()
+				.map(|(index, hash)| format!("\n\t\t{} => Some({}),", hash, index))
+				.collect::<Vec<String>>()
+				.join("")
+		),
+	)
+	.expect("Could not write to godot_class_indices.rs");
+}
+
 /// Generates and writes the Rust code for `OUT_DIR/add_components_for_node.rs`.
 fn generate_file(out_dir: OsString, entries: Vec<String>) {
 	let dest_path = Path::new(&out_dir).join(OUTPUT_FILE_NAME);
@@ -2,37 +2,55 @@ use bevy::prelude::*;
 use godot::prelude::*;
 
 pub trait GdTrackerId {
-	fn new(id: usize) -> Self;
+	/// Creates an id for the slot `id` of a tracker. `generation` is the number of times the
+	/// slot was reused, so ids to an object that was removed can be told apart.
+	fn new(id: usize, generation: u32) -> Self;
+
+	/// The slot of the tracker this id refers to.
+	fn index(&self) -> usize;
+
+	/// The generation of the slot when this id was created.
+	fn generation(&self) -> u32;
 }
 
 macro_rules! define_tracker_id {
-	($name: ident, $type: ty, $tracker: ty) => {
+	($(#[$attr: meta])* $name: ident, $type: ty, $tracker: ty) => {
 		#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
+		$(#[$attr])*
 		pub struct $name {
 			id: usize,
+			generation: u32,
 		}
 
 		impl $name {
 			pub fn get_as<T: Inherits<$type>>(&self, all_nodes: &$tracker) -> Gd<T> {
-				all_nodes.get(self.id).try_cast::<T>().unwrap()
+				all_nodes.get(self.id, self.generation).try_cast::<T>().unwrap()
 			}
 
 			pub fn try_get_as<T: Inherits<$type>>(&self, all_nodes: &$tracker) -> Option<Gd<T>> {
 				all_nodes
-					.try_get(self.id)
+					.try_get(self.id, self.generation)
 					.and_then(|n| n.try_cast::<T>().ok())
 			}
 		}
 
 		impl GdTrackerId for $name {
-			fn new(id: usize) -> Self {
-				Self { id }
+			fn new(id: usize, generation: u32) -> Self {
+				Self { id, generation }
+			}
+
+			fn index(&self) -> usize {
+				self.id
+			}
+
+			fn generation(&self) -> u32 {
+				self.generation
 			}
 		}
 
 		impl std::fmt::Display for $name {
 			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-				write!(f, "{}({})", stringify!($name), self.id)
+				write!(f, "{}({}v{})", stringify!($name), self.id, self.generation)
 			}
 		}
 	};
@@ -44,6 +62,10 @@ define_tracker_id!(
 	crate::resources::gd_tracker::AllNodes
 );
 define_tracker_id!(
+	#[component(
+		on_insert = crate::components::godot_resource_holder::retain_godot_resources::<Self>,
+		on_replace = crate::components::godot_resource_holder::release_godot_resources::<Self>,
+	)]
 	GodotResourceId,
 	godot::prelude::Resource,
 	crate::resources::gd_tracker::AllResources
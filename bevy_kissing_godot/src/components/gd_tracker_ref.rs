@@ -0,0 +1,152 @@
+use crate::components::{
+	gd_tracker_id::{GodotNodeId, GodotResourceId},
+	godot_resource_holder::GodotResourceHolder,
+};
+use crate::kissing_component::kissing_component_field::node_path_for_warning;
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+macro_rules! define_tracker_ref {
+	($(#[$attr: meta])* $name: ident, $id: ty, $type: ty, $tracker: ty, $kind: literal) => {
+		/// A
+		#[doc = concat!("[`", stringify!($id), "`]")]
+		/// that was validated to be a `T` when it was created, so [`Self::get`] can return
+		/// `Gd<T>` directly instead of an untyped object the caller has to cast.
+		///
+		/// It can be used as the type of
+		#[doc = concat!("`#[export_", $kind, "]`")]
+		/// fields, where a class mismatch is reported once when the component is built.
+		#[derive(Component)]
+		$(#[$attr])*
+		pub struct $name<T: Inherits<$type>> {
+			id: $id,
+			_class: PhantomData<T>,
+		}
+
+		/// Contains PhantomData, so safe to send across threads.
+		unsafe impl<T: Inherits<$type>> Send for $name<T> {}
+
+		/// Contains PhantomData, so safe to send across threads.
+		unsafe impl<T: Inherits<$type>> Sync for $name<T> {}
+
+		impl<T: Inherits<$type>> Clone for $name<T> {
+			fn clone(&self) -> Self {
+				*self
+			}
+		}
+
+		impl<T: Inherits<$type>> Copy for $name<T> {}
+
+		impl<T: Inherits<$type>> PartialEq for $name<T> {
+			fn eq(&self, other: &Self) -> bool {
+				self.id == other.id
+			}
+		}
+
+		impl<T: Inherits<$type>> std::fmt::Debug for $name<T> {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(f, "{}<{}>({})", stringify!($name), T::class_id(), self.id)
+			}
+		}
+
+		impl<T: Inherits<$type>> $name<T> {
+			/// Registers `gd_object`, which is already known to be a `T`.
+			pub fn new(gd_object: &Gd<T>, tracker: &mut $tracker) -> Self {
+				Self {
+					id: tracker.get_or_register_id_from_gd_object(&gd_object.clone().upcast()),
+					_class: PhantomData,
+				}
+			}
+
+			/// Registers `gd_object` if it's a `T`, otherwise returns it back.
+			pub fn try_new(gd_object: Gd<$type>, tracker: &mut $tracker) -> Result<Self, Gd<$type>> {
+				gd_object
+					.try_cast::<T>()
+					.map(|gd_object| Self::new(&gd_object, tracker))
+			}
+
+			/// Builds the reference for the field `field_name` of `component_name` on `node`.
+			///
+			/// If `gd_object` isn't a `T`, a warning naming the node, component and field is
+			/// printed and `None` is returned.
+			/// `pub` since used in "user code" generated via macro code.
+			pub fn from_export_field(
+				node: &Gd<Node>,
+				component_name: &str,
+				field_name: &str,
+				gd_object: Gd<$type>,
+				tracker: &mut $tracker,
+			) -> Option<Self> {
+				let class = gd_object.get_class();
+				match Self::try_new(gd_object, tracker) {
+					Ok(reference) => Some(reference),
+					Err(_) => {
+						godot_warn!(
+							"Field {} of Bevy Component {} on {} is a {}, not a {}.",
+							field_name,
+							component_name,
+							node_path_for_warning(node),
+							class,
+							T::class_id()
+						);
+						None
+					}
+				}
+			}
+
+			/// Returns the `Gd<T>`.
+			///
+			/// # Panics
+			///
+			/// Panics if it was removed from the tracker, such as a node that was freed. Use
+			/// [`Self::try_get`] if that can happen.
+			pub fn get(&self, tracker: &$tracker) -> Gd<T> {
+				self.id.get_as::<T>(tracker)
+			}
+
+			/// Returns the `Gd<T>`, or `None` if it was removed from the tracker.
+			pub fn try_get(&self, tracker: &$tracker) -> Option<Gd<T>> {
+				self.id.try_get_as::<T>(tracker)
+			}
+
+			/// Returns the untyped id.
+			pub fn id(&self) -> $id {
+				self.id
+			}
+		}
+
+		impl<T: Inherits<$type>> From<$name<T>> for $id {
+			fn from(reference: $name<T>) -> Self {
+				reference.id
+			}
+		}
+	};
+}
+
+define_tracker_ref!(
+	GodotNodeRef,
+	GodotNodeId,
+	godot::prelude::Node,
+	crate::resources::gd_tracker::AllNodes,
+	"node"
+);
+define_tracker_ref!(
+	#[component(
+		on_insert = crate::components::godot_resource_holder::retain_godot_resources::<Self>,
+		on_replace = crate::components::godot_resource_holder::release_godot_resources::<Self>,
+	)]
+	GodotResourceRef,
+	GodotResourceId,
+	godot::prelude::Resource,
+	crate::resources::gd_tracker::AllResources,
+	"resource"
+);
+
+impl<T: Inherits<godot::prelude::Resource>> GodotResourceHolder for GodotResourceRef<T> {
+	fn godot_resource_ids(&self) -> Vec<GodotResourceId> {
+		vec![self.id]
+	}
+}
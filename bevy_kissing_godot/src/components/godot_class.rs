@@ -0,0 +1,218 @@
+use std::marker::PhantomData;
+
+use bevy::{
+	ecs::{
+		archetype::Archetype,
+		change_detection::Tick,
+		component::{ComponentId, Components},
+		query::{FilteredAccess, QueryData, QueryFilter, WorldQuery},
+		storage::{Table, TableRow},
+		world::unsafe_world_cell::UnsafeWorldCell,
+	},
+	prelude::*,
+};
+use godot::obj::{self, NoBase};
+
+// -------------------------
+// * Top-Level Macro Calls *
+// -------------------------
+
+// Provides `GODOT_CLASS_COUNT` and `engine_class_index`.
+include!(concat!(env!("OUT_DIR"), "/godot_class_indices.rs"));
+
+// -------------
+// * Constants *
+// -------------
+
+/// The number of `u64`s needed to store a bit for every Godot engine class.
+const ANCESTRY_WORDS: usize = GODOT_CLASS_COUNT.div_ceil(64);
+
+// -----------
+// * Structs *
+// -----------
+
+/// The Godot class of an entity's node, used instead of a `GodotNode<T>` for the class and
+/// each of its ancestors when the `godot_class` feature is enabled.
+///
+/// Use [`IsA`] to filter queries by class:
+/// ```rust
+/// fn system(meshes: Query<&GodotNodeId, IsA<MeshInstance3D>>) {}
+/// ```
+#[derive(Component, Clone, PartialEq, Eq)]
+pub struct GodotClass {
+	/// The `StringName` hash of the class name.
+	class_hash: u32,
+
+	/// A bit for the class and each engine class it inherits from.
+	ancestry: [u64; ANCESTRY_WORDS],
+}
+
+impl GodotClass {
+	/// Constructs a `GodotClass` given the indices of the engine classes in its hierarchy.
+	/// Used by the build-generated `add_components_for_node`.
+	pub(crate) fn new(class_hash: u32, ancestry_indices: &[usize]) -> Self {
+		let mut ancestry = [0; ANCESTRY_WORDS];
+		for index in ancestry_indices {
+			ancestry[index / 64] |= 1 << (index % 64);
+		}
+		Self {
+			class_hash,
+			ancestry,
+		}
+	}
+
+	/// Constructs a `GodotClass` for `T`, such as a user-made `KissingNode` class, by walking
+	/// its bases.
+	pub fn of<T: obj::GodotClass>() -> Self {
+		let mut result = Self::new(class_hash::<T>(), &[]);
+		result.add_ancestry::<T>();
+		result
+	}
+
+	/// Returns `true` if the node's class is `T` or inherits from `T`.
+	pub fn is_a<T: obj::GodotClass>(&self) -> bool {
+		self.matches(&ClassMatch::of::<T>())
+	}
+
+	fn add_ancestry<T: obj::GodotClass>(&mut self) {
+		if T::class_id() == NoBase::class_id() {
+			return;
+		}
+		if let Some(index) = engine_class_index(class_hash::<T>()) {
+			self.ancestry[index / 64] |= 1 << (index % 64);
+		}
+		self.add_ancestry::<T::Base>();
+	}
+
+	fn matches(&self, class_match: &ClassMatch) -> bool {
+		match *class_match {
+			ClassMatch::Engine(index) => self.ancestry[index / 64] & (1 << (index % 64)) != 0,
+			ClassMatch::Exact(class_hash) => self.class_hash == class_hash,
+		}
+	}
+}
+
+impl std::fmt::Debug for GodotClass {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("GodotClass")
+			.field("class_hash", &self.class_hash)
+			.finish_non_exhaustive()
+	}
+}
+
+/// A query filter for entities whose node is of Godot class `T` or inherits from it.
+///
+/// Requires the `godot_class` feature. Unlike `With<GodotNode<T>>`, this checks the
+/// entity's [`GodotClass`] per entity instead of matching archetypes.
+///
+/// Only inheritance between engine classes is followed. If `T` is a custom class, only
+/// nodes of exactly that class match, not nodes of classes that extend it.
+pub struct IsA<T: obj::GodotClass>(PhantomData<T>);
+
+/// How [`IsA`] checks a [`GodotClass`].
+#[derive(Clone, Copy)]
+pub enum ClassMatch {
+	/// An engine class, checked using its bit in the ancestry.
+	Engine(usize),
+
+	/// A class that isn't from the engine, so only nodes of exactly that class match.
+	Exact(u32),
+}
+
+impl ClassMatch {
+	fn of<T: obj::GodotClass>() -> Self {
+		let class_hash = class_hash::<T>();
+		engine_class_index(class_hash).map_or(Self::Exact(class_hash), Self::Engine)
+	}
+}
+
+// ----------------------
+// * Query Filter Impls *
+// ----------------------
+
+// SAFETY: All access is delegated to `&GodotClass`, which registers read access for
+// `GodotClass` and only matches archetypes containing it.
+unsafe impl<T: obj::GodotClass> WorldQuery for IsA<T> {
+	type Fetch<'w> = <&'static GodotClass as WorldQuery>::Fetch<'w>;
+	type State = (ComponentId, ClassMatch);
+
+	fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+		<&GodotClass as WorldQuery>::shrink_fetch(fetch)
+	}
+
+	unsafe fn init_fetch<'w, 's>(
+		world: UnsafeWorldCell<'w>,
+		state: &'s Self::State,
+		last_run: Tick,
+		this_run: Tick,
+	) -> Self::Fetch<'w> {
+		// SAFETY: Upheld by the caller.
+		unsafe { <&GodotClass as WorldQuery>::init_fetch(world, &state.0, last_run, this_run) }
+	}
+
+	const IS_DENSE: bool = <&GodotClass as WorldQuery>::IS_DENSE;
+
+	unsafe fn set_archetype<'w, 's>(
+		fetch: &mut Self::Fetch<'w>,
+		state: &'s Self::State,
+		archetype: &'w Archetype,
+		table: &'w Table,
+	) {
+		// SAFETY: Upheld by the caller.
+		unsafe { <&GodotClass as WorldQuery>::set_archetype(fetch, &state.0, archetype, table) }
+	}
+
+	unsafe fn set_table<'w, 's>(fetch: &mut Self::Fetch<'w>, state: &'s Self::State, table: &'w Table) {
+		// SAFETY: Upheld by the caller.
+		unsafe { <&GodotClass as WorldQuery>::set_table(fetch, &state.0, table) }
+	}
+
+	fn update_component_access(state: &Self::State, access: &mut FilteredAccess) {
+		<&GodotClass as WorldQuery>::update_component_access(&state.0, access);
+	}
+
+	fn init_state(world: &mut World) -> Self::State {
+		(
+			<&GodotClass as WorldQuery>::init_state(world),
+			ClassMatch::of::<T>(),
+		)
+	}
+
+	fn get_state(components: &Components) -> Option<Self::State> {
+		Some((
+			<&GodotClass as WorldQuery>::get_state(components)?,
+			ClassMatch::of::<T>(),
+		))
+	}
+
+	fn matches_component_set(
+		state: &Self::State,
+		set_contains_id: &impl Fn(ComponentId) -> bool,
+	) -> bool {
+		<&GodotClass as WorldQuery>::matches_component_set(&state.0, set_contains_id)
+	}
+}
+
+// SAFETY: `filter_fetch` only reads `GodotClass`, which `update_component_access` registers.
+unsafe impl<T: obj::GodotClass> QueryFilter for IsA<T> {
+	const IS_ARCHETYPAL: bool = false;
+
+	unsafe fn filter_fetch(
+		state: &Self::State,
+		fetch: &mut Self::Fetch<'_>,
+		entity: Entity,
+		table_row: TableRow,
+	) -> bool {
+		// SAFETY: Upheld by the caller.
+		unsafe { <&GodotClass as QueryData>::fetch(&state.0, fetch, entity, table_row) }
+			.is_some_and(|class| class.matches(&state.1))
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+fn class_hash<T: obj::GodotClass>() -> u32 {
+	T::class_id().to_string_name().hash_u32()
+}
@@ -0,0 +1,153 @@
+use std::{
+	collections::{BTreeSet, HashMap},
+	sync::LazyLock,
+};
+
+use bevy::prelude::*;
+
+// -------------------------
+// * Top-Level Macro Calls *
+// -------------------------
+
+inventory::collect!(GodotGroupMarkerEntry);
+
+// -------------------------
+// * Top-Level Static Vars *
+// -------------------------
+
+/// A `HashMap` that, given the name of a Godot group, returns the inventory entries of
+/// its marker components.
+static GROUP_NAME_TO_MARKERS: LazyLock<
+	HashMap<&'static str, Vec<&'static GodotGroupMarkerEntry>>,
+> = LazyLock::new(|| {
+	let mut group_name_to_markers =
+		HashMap::<&'static str, Vec<&'static GodotGroupMarkerEntry>>::new();
+	for marker in inventory::iter::<GodotGroupMarkerEntry>() {
+		group_name_to_markers.entry(marker.group).or_default().push(marker);
+	}
+	group_name_to_markers
+});
+
+// ----------
+// * Macros *
+// ----------
+
+/// Registers a [`GodotGroupMarker`] so [`crate::plugins::godot_groups_plugin::GodotGroupsPlugin`]
+/// inserts it on entities whose node is in its group.
+///
+/// ```rust
+/// #[derive(Component, Default)]
+/// struct Enemy;
+///
+/// impl GodotGroupMarker for Enemy {
+///     const GROUP: &'static str = "enemies";
+/// }
+///
+/// godot_group_marker!(Enemy);
+/// ```
+#[macro_export]
+macro_rules! godot_group_marker {
+	($ty: ty) => {
+		$crate::prelude::bevy_kissing_godot_inventory::submit! {
+			$crate::components::godot_groups::GodotGroupMarkerEntry::new::<$ty>()
+		}
+	};
+}
+
+// ----------------
+// * Type Aliases *
+// ----------------
+
+/// A reference to a generic function that inserts or removes a marker component.
+type GodotGroupMarkerCallback = for<'a> fn(entity: &mut EntityWorldMut<'a>);
+
+// ----------
+// * Traits *
+// ----------
+
+/// A marker component for the Godot group [`GodotGroupMarker::GROUP`], so the group can be
+/// used in query filters like `With<Enemy>`.
+///
+/// It's implemented by `#[derive(GodotGroupMarker)]` with `#[godot_group("enemies")]`, or
+/// manually with [`crate::godot_group_marker!`].
+pub trait GodotGroupMarker: Component + Default {
+	const GROUP: &'static str;
+}
+
+// -----------
+// * Structs *
+// -----------
+
+/// The Godot groups of an entity's node.
+///
+/// Inserted and kept in sync by [`crate::plugins::godot_groups_plugin::GodotGroupsPlugin`].
+/// Groups changed with the `AddToGroup`/`RemoveFromGroup` events are synced, but groups
+/// changed directly on the node (such as from GDScript) are not.
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+pub struct GodotGroups(BTreeSet<String>);
+
+impl GodotGroups {
+	/// Returns `true` if the node is in `group`.
+	pub fn contains(&self, group: &str) -> bool {
+		self.0.contains(group)
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &str> {
+		self.0.iter().map(String::as_str)
+	}
+}
+
+/// Used by inventory to find the marker components of each Godot group.
+pub struct GodotGroupMarkerEntry {
+	group: &'static str,
+	insert: GodotGroupMarkerCallback,
+	remove: GodotGroupMarkerCallback,
+}
+
+impl GodotGroupMarkerEntry {
+	pub const fn new<T: GodotGroupMarker>() -> Self {
+		Self {
+			group: T::GROUP,
+			insert: insert_marker::<T>,
+			remove: remove_marker::<T>,
+		}
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Adds or removes `group` in the entity's [`GodotGroups`], and inserts or removes the
+/// group's marker components to match.
+pub(crate) fn set_godot_group(entity: &mut EntityWorldMut, group: &str, in_group: bool) {
+	if !entity.contains::<GodotGroups>() {
+		entity.insert(GodotGroups::default());
+	}
+	if let Some(mut groups) = entity.get_mut::<GodotGroups>() {
+		if in_group {
+			groups.0.insert(group.to_string());
+		} else {
+			groups.0.remove(group);
+		}
+	}
+
+	let Some(markers) = GROUP_NAME_TO_MARKERS.get(group) else {
+		return;
+	};
+	for marker in markers {
+		if in_group {
+			(marker.insert)(entity);
+		} else {
+			(marker.remove)(entity);
+		}
+	}
+}
+
+fn insert_marker<T: GodotGroupMarker>(entity: &mut EntityWorldMut) {
+	entity.insert(T::default());
+}
+
+fn remove_marker<T: GodotGroupMarker>(entity: &mut EntityWorldMut) {
+	entity.remove::<T>();
+}
@@ -4,6 +4,8 @@ use bevy::prelude::*;
 use godot::obj::{GodotClass, NoBase};
 
 /// Used to mark an entity as having a `GodotNodeId` of type or descendant of `T`
+///
+/// Not inserted when the `godot_class` feature is enabled; use `IsA<T>` instead.
 #[derive(Component)]
 pub struct GodotNode<T>(PhantomData<T>);
 
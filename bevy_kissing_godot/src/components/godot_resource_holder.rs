@@ -0,0 +1,79 @@
+use crate::components::gd_tracker_id::GodotResourceId;
+use crate::resources::gd_tracker::AllResources;
+
+use bevy::{
+	ecs::{lifecycle::HookContext, world::DeferredWorld},
+	prelude::*,
+};
+
+// ----------
+// * Traits *
+// ----------
+
+/// A component that stores `GodotResourceId`s.
+///
+/// While the component is on an entity, its resources are retained in [`AllResources`].
+/// Once no component retains a resource anymore, it's removed from [`AllResources`] at the
+/// end of the frame so Godot can free it.
+///
+/// `#[derive(KissingComponent)]` implements it for components with `#[export_resource]` fields
+/// and registers the hooks when the app is built. For other components, implement it and
+/// register the hooks on the component:
+/// ```rust
+/// #[derive(Component)]
+/// #[component(
+///     on_insert = retain_godot_resources::<Self>,
+///     on_replace = release_godot_resources::<Self>,
+/// )]
+/// struct Sprite {
+///     texture: GodotResourceId,
+/// }
+///
+/// impl GodotResourceHolder for Sprite {
+///     fn godot_resource_ids(&self) -> Vec<GodotResourceId> {
+///         vec![self.texture]
+///     }
+/// }
+/// ```
+pub trait GodotResourceHolder: Component {
+	fn godot_resource_ids(&self) -> Vec<GodotResourceId>;
+}
+
+impl GodotResourceHolder for GodotResourceId {
+	fn godot_resource_ids(&self) -> Vec<GodotResourceId> {
+		vec![*self]
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// The `on_insert` hook of a [`GodotResourceHolder`].
+/// `pub` since used in "user code" generated via macro code.
+pub fn retain_godot_resources<C: GodotResourceHolder>(mut world: DeferredWorld, context: HookContext) {
+	update_godot_resources::<C>(&mut world, context.entity, AllResources::retain);
+}
+
+/// The `on_replace` hook of a [`GodotResourceHolder`], which also runs when it's removed
+/// or its entity is despawned.
+/// `pub` since used in "user code" generated via macro code.
+pub fn release_godot_resources<C: GodotResourceHolder>(mut world: DeferredWorld, context: HookContext) {
+	update_godot_resources::<C>(&mut world, context.entity, AllResources::release);
+}
+
+fn update_godot_resources<C: GodotResourceHolder>(
+	world: &mut DeferredWorld,
+	entity: Entity,
+	update: fn(&mut AllResources, &GodotResourceId),
+) {
+	let Some(ids) = world.get::<C>(entity).map(C::godot_resource_ids) else {
+		return;
+	};
+	let Some(mut all_resources) = world.get_non_send_resource_mut::<AllResources>() else {
+		return;
+	};
+	for id in &ids {
+		update(&mut all_resources, id);
+	}
+}
@@ -1,2 +1,8 @@
 pub mod gd_tracker_id;
+pub mod gd_tracker_ref;
+pub mod godot_groups;
 pub mod godot_node;
+pub mod godot_resource_holder;
+
+#[cfg(feature = "godot_class")]
+pub mod godot_class;
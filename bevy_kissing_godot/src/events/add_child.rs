@@ -25,7 +25,7 @@ impl AddChild {
 
 pub(crate) fn on_add_child(
 	event: On<AddChild>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	if event.entity == event.child {
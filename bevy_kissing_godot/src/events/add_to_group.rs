@@ -0,0 +1,46 @@
+use super::utils::get_node;
+use crate::{
+	components::godot_groups::set_godot_group,
+	entity_or_node_id::EntityOrNodeId,
+	prelude::{AllNodes, GodotNodeId, NodeEntityMap},
+};
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+/// Runs `add_to_group` on the Godot node that correlates to the provided Bevy entity,
+/// and updates its `GodotGroups` and group markers.
+///
+/// Requires [`crate::plugins::godot_groups_plugin::GodotGroupsPlugin`].
+#[derive(Event)]
+pub struct AddToGroup {
+	entity_or_node_id: EntityOrNodeId,
+	group: String,
+}
+
+impl AddToGroup {
+	pub fn new(entity_or_node_id: EntityOrNodeId, group: impl Into<String>) -> Self {
+		Self {
+			entity_or_node_id,
+			group: group.into(),
+		}
+	}
+}
+
+pub(crate) fn on_add_to_group(
+	event: On<AddToGroup>,
+	nodes: Query<&GodotNodeId>,
+	all_nodes: NonSend<AllNodes>,
+	node_entity_map: NonSend<NodeEntityMap>,
+	mut commands: Commands,
+) -> bevy::prelude::Result<()> {
+	let mut node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
+	node.add_to_group(event.group.as_str());
+	if let Some(entity) = node_entity_map.entity_for(&node) {
+		let group = event.group.clone();
+		commands
+			.entity(entity)
+			.queue(move |mut entity: EntityWorldMut| set_godot_group(&mut entity, &group, true));
+	}
+	Ok(())
+}
@@ -1,6 +1,9 @@
 mod error;
 mod utils;
 
+pub mod add_to_group;
+pub mod remove_from_group;
+
 #[cfg(feature = "node_triggerables")]
 pub mod add_child;
 
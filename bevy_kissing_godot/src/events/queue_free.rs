@@ -20,7 +20,7 @@ impl QueueFree {
 
 pub(crate) fn on_queue_free(
 	event: On<QueueFree>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let mut node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
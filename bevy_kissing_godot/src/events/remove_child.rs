@@ -25,7 +25,7 @@ impl RemoveChild {
 
 pub(crate) fn on_remove_child(
 	event: On<RemoveChild>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	if event.entity == event.child {
@@ -61,7 +61,7 @@ impl<T: GodotClass + Inherits<Node>> RunCodeOnNode<T> {
 
 fn on_run_code_on_node<T: GodotClass + Inherits<Node>>(
 	event: On<RunCodeOnNode<T>>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
@@ -75,7 +75,7 @@ fn on_run_code_on_node<T: GodotClass + Inherits<Node>>(
 
 pub(crate) fn on_run_code_on_untyped_node(
 	event: On<RunCodeOnNode<Node>>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
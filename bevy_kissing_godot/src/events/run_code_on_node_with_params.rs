@@ -80,7 +80,7 @@ fn on_run_code_on_node_with_params<
 	Params: Send + Sync + 'static,
 >(
 	event: On<RunCodeOnNodeWithParams<T, Params>>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
@@ -42,7 +42,7 @@ impl<T: ToGodot + Send + Sync + 'static> SetGodotProperty<T> {
 
 pub(crate) fn on_set_godot_property<T: ToGodot + Send + Sync + 'static>(
 	event: On<SetGodotProperty<T>>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let mut node = get_node::<Node>(event.entity_or_node_id, nodes, &all_nodes)?;
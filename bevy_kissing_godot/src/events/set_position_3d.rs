@@ -24,7 +24,7 @@ impl SetPosition3D {
 
 pub(crate) fn on_set_position_3d(
 	event: On<SetPosition3D>,
-	nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: NonSend<AllNodes>,
 ) -> bevy::prelude::Result<()> {
 	let mut node_3d = get_node::<Node3D>(event.entity_or_node_id, nodes, &all_nodes)?;
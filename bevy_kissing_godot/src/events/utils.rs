@@ -9,11 +9,11 @@ use godot::prelude::*;
 
 pub(super) fn get_node<T: Inherits<Node>>(
 	entity_or_node_id: EntityOrNodeId,
-	mut nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: &AllNodes,
 ) -> Result<Gd<T>, Error> {
 	let id = match entity_or_node_id {
-		EntityOrNodeId::Entity(entity) => nodes.get_mut(entity).map(|id| *id).ok(),
+		EntityOrNodeId::Entity(entity) => nodes.get(entity).ok().copied(),
 		EntityOrNodeId::NodeId(godot_node_id) => Some(godot_node_id),
 	};
 
@@ -27,11 +27,11 @@ pub(super) fn get_node<T: Inherits<Node>>(
 pub(super) fn get_parent_and_child<Parent: Inherits<Node>, Child: Inherits<Node>>(
 	parent_entity_or_node_id: EntityOrNodeId,
 	child_entity_or_node_id: EntityOrNodeId,
-	mut nodes: Query<&mut GodotNodeId>,
+	nodes: Query<&GodotNodeId>,
 	all_nodes: &AllNodes,
 ) -> Result<(Gd<Parent>, Gd<Child>), Error> {
 	let parent_id = match parent_entity_or_node_id {
-		EntityOrNodeId::Entity(entity) => nodes.get_mut(entity).map(|id| *id).ok(),
+		EntityOrNodeId::Entity(entity) => nodes.get(entity).ok().copied(),
 		EntityOrNodeId::NodeId(godot_node_id) => Some(godot_node_id),
 	};
 	let Some(parent) = parent_id.and_then(|id| id.try_get_as::<Parent>(&all_nodes)) else {
@@ -39,7 +39,7 @@ pub(super) fn get_parent_and_child<Parent: Inherits<Node>, Child: Inherits<Node>
 	};
 
 	let child_id = match child_entity_or_node_id {
-		EntityOrNodeId::Entity(entity) => nodes.get_mut(entity).map(|id| *id).ok(),
+		EntityOrNodeId::Entity(entity) => nodes.get(entity).ok().copied(),
 		EntityOrNodeId::NodeId(godot_node_id) => Some(godot_node_id),
 	};
 	let Some(child) = child_id.and_then(|id| id.try_get_as::<Child>(&all_nodes)) else {
@@ -1,13 +1,21 @@
 use crate::kissing_component::kissing_component_bridge;
+use crate::kissing_function::kissing_function::FUNCTION_NAME_TO_FUNC;
+use crate::kissing_function::kissing_function_error::KissingFunctionError;
+use crate::kissing_resource::kissing_resource::RESOURCE_NAME_TO_KISSING_RESOURCE;
+use crate::kissing_settings::kissing_settings;
 use crate::nodes::command_queue_node::CommandQueueNode;
 use crate::nodes::tree_responder::TreeResponder;
 use crate::prelude::*;
 use crate::resources::entity_preregister::EntityPreregister;
 use crate::resources::gd_tracker::AllNodes;
+use crate::resources::node_entity_map::NodeEntityMap;
+use crate::resources::node_path_cache::NodePathCache;
+use crate::resources::pending_entity_references::PendingEntityReferences;
 
-use std::collections::BTreeMap;
+use std::cell::Cell;
 
-use bevy::prelude::*;
+use bevy::{ecs::change_detection::Tick, prelude::*};
+use godot::classes::ProjectSettings;
 use godot::prelude::*;
 
 // -----------------------
@@ -32,6 +40,42 @@ include!(concat!(env!("OUT_DIR"), "/add_components_for_node.rs"));
 
 pub(crate) const COMMAND_QUEUE_NODE_NAME: &str = "BevyKissingGodot_CommandQueueNode";
 
+/// Emitted by the "App" node with `(resource_name, data)` when a `KissingResource` changes.
+/// Declared with `#[signal]` in the `#[kiss_bevy]` node.
+pub(crate) const RESOURCE_CHANGED_SIGNAL_NAME: &str = "bevy_resource_changed";
+
+// -------------------------
+// * Top-Level Static Vars *
+// -------------------------
+
+thread_local! {
+	/// `true` while the Bevy app is running, so re-entrant calls from Godot can be rejected.
+	static IS_PROCESSING: Cell<bool> = const { Cell::new(false) };
+}
+
+// -------------------
+// * ProcessingGuard *
+// -------------------
+
+/// Marks the Bevy app as processing until dropped.
+struct ProcessingGuard {
+	was_processing: bool,
+}
+
+impl ProcessingGuard {
+	fn new() -> Self {
+		Self {
+			was_processing: IS_PROCESSING.replace(true),
+		}
+	}
+}
+
+impl Drop for ProcessingGuard {
+	fn drop(&mut self) {
+		IS_PROCESSING.set(self.was_processing);
+	}
+}
+
 // --------------
 // * KissingApp *
 // --------------
@@ -41,18 +85,22 @@ pub(crate) const COMMAND_QUEUE_NODE_NAME: &str = "BevyKissingGodot_CommandQueueN
 /// Most of the code is kept here to prevent it from being implemented within a macro.
 pub struct KissingApp {
 	bevy_app: Option<App>,
+	app_node: Option<Gd<Node>>,
 	tree_responder: Option<Gd<TreeResponder>>,
 	command_queue: Option<Gd<CommandQueueNode>>,
-	node_id_to_bevy_entity: BTreeMap<InstanceId, Entity>,
+
+	/// The change tick [`RESOURCE_CHANGED_SIGNAL_NAME`] was last emitted at.
+	resource_signals_tick: Tick,
 }
 
 impl Default for KissingApp {
 	fn default() -> Self {
 		Self {
 			bevy_app: Some(bevy::prelude::App::new()),
+			app_node: None,
 			tree_responder: None,
 			command_queue: None,
-			node_id_to_bevy_entity: BTreeMap::new(),
+			resource_signals_tick: Tick::new(0),
 		}
 	}
 }
@@ -81,12 +129,17 @@ impl KissingApp {
 		#[cfg(feature = "input")]
 		bevy_app.add_plugins(crate::plugins::kissing_input_plugin::KissingInputPlugin);
 
+		kissing_settings::load_kissing_settings(bevy_app.world_mut());
+
 		//self.bevy_app = bevy_app.into();
 	}
 
 	/// Called immediately after the `#[kiss_bevy]` function implemented by the user.
 	pub fn post_ready(&mut self, app_node: Gd<Node>, tree: Gd<SceneTree>) {
+		self.app_node = Some(app_node.clone());
+
 		self.init_command_queue(app_node.clone());
+		self.init_settings_reloader();
 		self.setup_scene_tree(tree.clone());
 		self.init_tree_responder(app_node, tree.clone());
 
@@ -130,6 +183,37 @@ impl KissingApp {
 		self.command_queue = Some(command_queue_node);
 	}
 
+	/// Re-reads settings resources whenever `ProjectSettings` changes, if any want to be.
+	///
+	/// Like [`init_command_queue`], the command queue node is bound as the last argument
+	/// so the reload can be queued until Bevy's world is free.
+	fn init_settings_reloader(&mut self) {
+		const CALLABLE_NAME: &str = "Bevy💋Godot settings_changed_callable";
+
+		if !kissing_settings::has_reloadable_kissing_settings() {
+			return;
+		}
+		let Some(command_queue_node) = self.command_queue.as_ref() else {
+			return;
+		};
+
+		let callable = Callable::from_fn(CALLABLE_NAME, |args| {
+			let Some(mut last) = args
+				.last()
+				.and_then(|v| v.try_to::<Gd<CommandQueueNode>>().ok())
+			else {
+				return;
+			};
+			last.bind_mut()
+				.push(kissing_settings::reload_kissing_settings);
+		});
+
+		ProjectSettings::singleton().connect(
+			"settings_changed",
+			&callable.bind(vslice![command_queue_node]),
+		);
+	}
+
 	/// Creates `TreeResponder` and connect it to the `SceneTree`'s signals.
 	fn init_tree_responder(&mut self, mut app_node: Gd<Node>, tree: Gd<SceneTree>) {
 		let mut tree_responder = TreeResponder::new_alloc();
@@ -151,11 +235,14 @@ impl KissingApp {
 		let Some(bevy_app) = self.bevy_app.as_mut() else {
 			return;
 		};
+		let _guard = ProcessingGuard::new();
 		bevy_app.world_mut().resource_mut::<ProcessDelta>().0 = delta;
 		bevy_app.world_mut().run_schedule(Process);
 		bevy_app.world_mut().clear_trackers();
+		self.emit_resource_changed_signals();
 		self.handle_tree_responder_events();
 		self.apply_command_queue();
+		self.release_unreferenced_resources();
 	}
 
 	/// Called every `_physics_process` of the user's Bevy app node.
@@ -163,10 +250,13 @@ impl KissingApp {
 		let Some(bevy_app) = self.bevy_app.as_mut() else {
 			return;
 		};
+		let _guard = ProcessingGuard::new();
 		bevy_app.world_mut().resource_mut::<PhysicsProcessDelta>().0 = delta;
 		bevy_app.world_mut().run_schedule(PhysicsProcess);
+		self.emit_resource_changed_signals();
 		self.handle_tree_responder_events();
 		self.apply_command_queue();
+		self.release_unreferenced_resources();
 	}
 
 	/// Called every `_input` of the user's Bevy app node.
@@ -175,6 +265,7 @@ impl KissingApp {
 		let Some(bevy_app) = self.bevy_app.as_mut() else {
 			return;
 		};
+		let _guard = ProcessingGuard::new();
 		if let Some(mut arg) = bevy_app
 			.world_mut()
 			.get_non_send_resource_mut::<InputEventArgument>()
@@ -200,14 +291,51 @@ impl KissingApp {
 		};
 		let added_nodes = tree_responder.bind_mut().take_added_nodes();
 		let removed_nodes = tree_responder.bind_mut().take_removed_nodes();
+		if !added_nodes.is_empty() || !removed_nodes.is_empty() {
+			if let Some(bevy_app) = self.bevy_app.as_mut() {
+				bevy_app
+					.world_mut()
+					.non_send_resource_mut::<NodePathCache>()
+					.clear();
+			}
+		}
 		for n in added_nodes {
 			self.on_node_added(n);
 		}
+		self.resolve_entity_references();
 		for n in removed_nodes {
 			self.on_node_removed(n);
 		}
 	}
 
+	/// Emits [`RESOURCE_CHANGED_SIGNAL_NAME`] for every `KissingResource` changed since the
+	/// signals were last emitted, so changes from either schedule are reported the same frame.
+	///
+	/// The signals are deferred so connected GDScript can safely call back into the app.
+	fn emit_resource_changed_signals(&mut self) {
+		let Some(bevy_app) = self.bevy_app.as_mut() else {
+			return;
+		};
+		let Some(app_node) = self.app_node.as_mut() else {
+			return;
+		};
+		let world = bevy_app.world_mut();
+		for (name, kissing_resource) in RESOURCE_NAME_TO_KISSING_RESOURCE.iter() {
+			if !(kissing_resource.get_is_resource_changed())(world, self.resource_signals_tick) {
+				continue;
+			}
+			let Some(data) = (kissing_resource.get_resource_to_dictionary())(world) else {
+				continue;
+			};
+			app_node.call_deferred(
+				"emit_signal",
+				vslice![RESOURCE_CHANGED_SIGNAL_NAME, name, data],
+			);
+		}
+		// Changes made directly on the world after this use the next tick, so they aren't missed.
+		self.resource_signals_tick = world.increment_change_tick();
+	}
+
 	/// Execute the commands in the queue.
 	fn apply_command_queue(&mut self) {
 		let Some(bevy_app) = self.bevy_app.as_mut() else {
@@ -223,6 +351,18 @@ impl KissingApp {
 		}
 	}
 
+	/// Removes the resources in `AllResources` that no component references anymore, so Godot
+	/// can free them (such as after the entities of a level are despawned).
+	fn release_unreferenced_resources(&mut self) {
+		let Some(bevy_app) = self.bevy_app.as_mut() else {
+			return;
+		};
+		bevy_app
+			.world_mut()
+			.non_send_resource_mut::<AllResources>()
+			.sweep();
+	}
+
 	/// Connected to `SceneTree`'s `node_added` signal.
 	pub fn on_node_added(&mut self, mut node_added: Gd<Node>) {
 		self.setup_node(&mut node_added);
@@ -239,12 +379,201 @@ impl KissingApp {
 			.non_send_resource_mut::<AllNodes>()
 			.remove(&node_removed);
 
-		if let Some(entity) = self.node_id_to_bevy_entity.remove(&node_removed) {
+		let entity = bevy_app
+			.world_mut()
+			.non_send_resource_mut::<NodeEntityMap>()
+			.remove(&node_removed);
+		if let Some(entity) = entity {
 			bevy_app.world_mut().despawn(entity);
 		}
 	}
 }
 
+/// `KissingApp` GDScript API.
+///
+/// This implementation contains the functions the `#[kiss_bevy]` node exposes to GDScript.
+/// They run synchronously, so they are only reachable while Bevy isn't already processing.
+impl KissingApp {
+	/// Returns `true` while the Bevy app is running a schedule or a `#[kissing_function]`.
+	///
+	/// The `#[kiss_bevy]` node checks this before binding itself, since a GDScript call made
+	/// from inside a Bevy system would otherwise panic on the already-bound node.
+	pub fn is_processing() -> bool {
+		IS_PROCESSING.get()
+	}
+
+	/// Prints the error for a call to the `#[kiss_bevy]` node's `function` made while Bevy
+	/// is processing.
+	pub fn print_app_busy_error(function: &str) {
+		godot_error!("Could not call {} (reason: the Bevy app is already processing).", function);
+	}
+
+	/// Prints the error for a `#[kissing_function]` call that couldn't run and returns `nil`.
+	pub fn kissing_function_failed(name: &StringName, error: KissingFunctionError) -> Variant {
+		godot_error!("Could not call Bevy function {} (reason: {}).", name, error);
+		Variant::nil()
+	}
+
+	/// Runs the `#[kissing_function]` system named `name` with `args` and returns its output.
+	///
+	/// Returns `nil` and prints an error if the function doesn't exist or fails.
+	pub fn call_kissing_function(&mut self, name: &StringName, args: &[&Variant]) -> Variant {
+		match self.try_call_kissing_function(name, args) {
+			Ok(result) => result,
+			Err(e) => Self::kissing_function_failed(name, e),
+		}
+	}
+
+	fn try_call_kissing_function(
+		&mut self,
+		name: &StringName,
+		args: &[&Variant],
+	) -> Result<Variant, KissingFunctionError> {
+		if Self::is_processing() {
+			return Err(KissingFunctionError::AppBusy);
+		}
+		let Some(bevy_app) = self.bevy_app.as_mut() else {
+			return Err(KissingFunctionError::NoApp);
+		};
+		let Some(call) = FUNCTION_NAME_TO_FUNC.get(name) else {
+			return Err(KissingFunctionError::NotFound(name.to_string()));
+		};
+		let _guard = ProcessingGuard::new();
+		call(bevy_app.world_mut(), args)
+	}
+
+	/// Returns the fields of the `KissingComponent` named `name` on `node`'s entity.
+	///
+	/// The dictionary uses the same format as the "data" in the "bevy_components" metadata.
+	/// Returns an empty dictionary and prints an error if the component can't be read.
+	pub fn get_component(&mut self, node: Gd<Node>, name: &StringName) -> VarDictionary {
+		let result = self.with_node_entity(&node, |world, entity| {
+			kissing_component_bridge::get_component(world, entity, name)
+		});
+		match result {
+			Some(Ok(data)) => data,
+			Some(Err(e)) => {
+				godot_error!("Could not get Bevy Component for {} (reason: {}).", node, e);
+				VarDictionary::new()
+			}
+			None => VarDictionary::new(),
+		}
+	}
+
+	/// Inserts or replaces the `KissingComponent` named `name` on `node`'s entity using `data`.
+	///
+	/// Returns `false` and prints an error if the component couldn't be set.
+	pub fn set_component(&mut self, mut node: Gd<Node>, name: &StringName, data: VarDictionary) -> bool {
+		let result = self.with_node_entity(&node.clone(), |world, entity| {
+			kissing_component_bridge::set_component(&mut node, world, entity, name, data)
+		});
+		match result {
+			Some(Ok(())) => true,
+			Some(Err(e)) => {
+				godot_error!("Could not set Bevy Component for {} (reason: {}).", node, e);
+				false
+			}
+			None => false,
+		}
+	}
+
+	/// Returns `true` if `node`'s entity has the `KissingComponent` named `name`.
+	pub fn has_component(&mut self, node: Gd<Node>, name: &StringName) -> bool {
+		let result = self.with_node_entity(&node, |world, entity| {
+			kissing_component_bridge::has_component(world, entity, name)
+		});
+		match result {
+			Some(Ok(has_component)) => has_component,
+			Some(Err(e)) => {
+				godot_error!("Could not check Bevy Component for {} (reason: {}).", node, e);
+				false
+			}
+			None => false,
+		}
+	}
+
+	/// Removes the `KissingComponent` named `name` from `node`'s entity.
+	///
+	/// Returns `true` if the component was present.
+	pub fn remove_component(&mut self, node: Gd<Node>, name: &StringName) -> bool {
+		let result = self.with_node_entity(&node, |world, entity| {
+			kissing_component_bridge::remove_component(world, entity, name)
+		});
+		match result {
+			Some(Ok(removed)) => removed,
+			Some(Err(e)) => {
+				godot_error!("Could not remove Bevy Component for {} (reason: {}).", node, e);
+				false
+			}
+			None => false,
+		}
+	}
+
+	/// Returns the exported fields of the `KissingResource` named `name`.
+	///
+	/// Returns an empty dictionary and prints an error if the resource can't be read.
+	pub fn get_resource(&mut self, name: &StringName) -> VarDictionary {
+		let Some(bevy_app) = self.bevy_app.as_ref() else {
+			return VarDictionary::new();
+		};
+		let Some(kissing_resource) = RESOURCE_NAME_TO_KISSING_RESOURCE.get(name) else {
+			godot_error!("Could not find Bevy Resource of name {}.", name);
+			return VarDictionary::new();
+		};
+		let Some(data) = (kissing_resource.get_resource_to_dictionary())(bevy_app.world()) else {
+			godot_error!("Bevy Resource {} does not exist in the world.", name);
+			return VarDictionary::new();
+		};
+		data
+	}
+
+	/// Returns the exported field `field` of the `KissingResource` named `name`, or `nil`.
+	pub fn get_resource_field(&mut self, name: &StringName, field: &StringName) -> Variant {
+		self.get_resource(name)
+			.get(field.to_string())
+			.unwrap_or_default()
+	}
+
+	/// Sets the exported field `field` of the `KissingResource` named `name`.
+	///
+	/// Returns `false` and prints an error if the field couldn't be set.
+	pub fn set_resource_field(&mut self, name: &StringName, field: &StringName, value: &Variant) -> bool {
+		let Some(bevy_app) = self.bevy_app.as_mut() else {
+			return false;
+		};
+		let Some(kissing_resource) = RESOURCE_NAME_TO_KISSING_RESOURCE.get(name) else {
+			godot_error!("Could not find Bevy Resource of name {}.", name);
+			return false;
+		};
+		let field = field.to_string();
+		if !(kissing_resource.get_set_resource_field())(bevy_app.world_mut(), &field, value) {
+			godot_error!("Could not set field {} of Bevy Resource {} to {}.", field, name, value);
+			return false;
+		}
+		true
+	}
+
+	/// Runs `f` with the world and the entity of `node`.
+	///
+	/// Returns `None` and prints an error if there is no app or `node` has no entity.
+	fn with_node_entity<R>(
+		&mut self,
+		node: &Gd<Node>,
+		f: impl FnOnce(&mut World, Entity) -> R,
+	) -> Option<R> {
+		let Some(bevy_app) = self.bevy_app.as_mut() else {
+			godot_error!("Could not access Bevy entity for {} (reason: no Bevy app is running).", node);
+			return None;
+		};
+		let world = bevy_app.world_mut();
+		let Some(entity) = world.non_send_resource::<NodeEntityMap>().entity_for(node) else {
+			godot_error!("Could not access Bevy entity for {} (reason: node has no entity).", node);
+			return None;
+		};
+		Some(f(world, entity))
+	}
+}
+
 /// `KissingApp` node set ups.
 ///
 /// This implementation contains functions for initializing Godot nodes and stuff.
@@ -259,6 +588,7 @@ impl KissingApp {
 		};
 
 		self.setup_scene(scene);
+		self.resolve_entity_references();
 	}
 
 	/// Set up the scene.
@@ -266,6 +596,44 @@ impl KissingApp {
 		self.setup_node_children(&mut scene);
 	}
 
+	/// Resolves the `#[export_entity]` fields deferred while setting up the last batch of nodes.
+	///
+	/// Must be called once every node in the batch has an entity.
+	fn resolve_entity_references(&mut self) {
+		let Some(world) = self.bevy_app.as_mut().map(|a| a.world_mut()) else {
+			return;
+		};
+
+		let pending = world
+			.non_send_resource_mut::<PendingEntityReferences>()
+			.take();
+		for reference in pending {
+			let node_entity_map = world.non_send_resource::<NodeEntityMap>();
+			let targets = reference
+				.targets
+				.iter()
+				.map(|target| {
+					let target = target.as_ref()?;
+					let entity = node_entity_map.entity_for_instance_id(*target);
+					if entity.is_none() {
+						godot_warn!(
+							"Field {} of Bevy Component {} on {} points to node {}, which has no entity, leaving it unset.",
+							reference.field_name,
+							reference.component_name,
+							reference.node_path,
+							target
+						);
+					}
+					entity
+				})
+				.collect::<Vec<Option<Entity>>>();
+
+			if let Ok(entity) = world.get_entity_mut(reference.source) {
+				(reference.apply)(entity, &targets);
+			}
+		}
+	}
+
 	/// Set up a node's children recusively.
 	fn setup_node_children(&mut self, parent_node: &Gd<Node>) {
 		for i in 0..parent_node.get_child_count() {
@@ -316,7 +684,8 @@ impl KissingApp {
 				.bevy_entity_ready(world.entity_mut(entity_id));
 		}
 
-		self.node_id_to_bevy_entity
-			.insert(node.instance_id(), entity_id);
+		world
+			.non_send_resource_mut::<NodeEntityMap>()
+			.insert(node, entity_id);
 	}
 }
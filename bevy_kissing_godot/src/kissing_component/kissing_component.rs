@@ -1,10 +1,17 @@
 use crate::{
-	kissing_component::kissing_component_data::KissingComponentData, kissing_registry::GetData,
+	components::godot_resource_holder::{
+		GodotResourceHolder, release_godot_resources, retain_godot_resources,
+	},
+	kissing_component::{
+		kissing_component_data::{KissingComponentData, enum_component_field_name},
+		kissing_component_error::KissingComponentError,
+	},
+	kissing_registry::GetData,
 };
 
 use std::{
-	collections::{BTreeMap, HashMap},
-	sync::LazyLock,
+	collections::{BTreeMap, HashMap, HashSet},
+	sync::{LazyLock, OnceLock},
 };
 
 use bevy::prelude::*;
@@ -21,21 +28,36 @@ inventory::collect!(KissingComponent);
 // -------------------------
 
 /// A `HashMap` that, given a name of a `KissingComponent`-derived `Component`,
-/// returns its `add_component_from_editor_fields`.
-pub static COMPONENT_NAME_TO_FUNC: LazyLock<
-	HashMap<StringName, &AddComponentFromEditorFieldsCallback>,
+/// returns its inventory entry.
+pub static COMPONENT_NAME_TO_KISSING_COMPONENT: LazyLock<
+	HashMap<StringName, &'static KissingComponent>,
 > = LazyLock::new(|| {
-	let mut component_name_to_func =
-		HashMap::<StringName, &AddComponentFromEditorFieldsCallback>::new();
+	let mut component_name_to_kissing_component =
+		HashMap::<StringName, &'static KissingComponent>::new();
+	for kissing_component in inventory::iter::<KissingComponent>() {
+		let data = kissing_component.get_data();
+		for alias in data.aliases {
+			component_name_to_kissing_component.insert(StringName::from(*alias), kissing_component);
+		}
+	}
+
+	// Insert real names last so they win over any alias that reuses them.
+	// Two components with the same name (like reflected components from different crates with
+	// the same short type path) can't both be looked up, so the first one is kept.
+	let mut real_names = HashSet::<StringName>::new();
 	for kissing_component in inventory::iter::<KissingComponent>() {
 		let data = kissing_component.get_data();
 		let name = StringName::from(data.name);
-		component_name_to_func.insert(
-			name,
-			kissing_component.get_add_component_from_editor_fields(),
-		);
+		if !real_names.insert(name.clone()) {
+			godot_error!(
+				"More than one Bevy Component is named {}, so only one of them can be used.",
+				name
+			);
+			continue;
+		}
+		component_name_to_kissing_component.insert(name, kissing_component);
 	}
-	component_name_to_func
+	component_name_to_kissing_component
 });
 
 // ----------------
@@ -48,7 +70,21 @@ type AddComponentFromEditorFieldsCallback = fn(
 	world: &mut World,
 	entity: &Entity,
 	fields: BTreeMap<String, Variant>,
-) -> bool;
+) -> Result<(), KissingComponentError>;
+
+/// A reference to a component's static function that converts the component on `entity` to
+/// the same dictionary format used by its editor fields.
+type ComponentToDictionaryCallback = fn(world: &World, entity: Entity) -> Option<VarDictionary>;
+
+/// A reference to a component's static function that upgrades stored editor fields saved with
+/// `from_version` to the component's current version.
+type MigrateEditorFieldsCallback = fn(from_version: u32, fields: &mut BTreeMap<String, Variant>);
+
+/// A reference to a generic function that checks for or removes a component on `entity`.
+type ComponentOnEntityCallback = fn(world: &mut World, entity: Entity) -> bool;
+
+/// A reference to a generic function that registers the component's hooks in `world`.
+type RegisterHooksCallback = fn(world: &mut World);
 
 // ----------------
 // * Structs *
@@ -57,7 +93,13 @@ type AddComponentFromEditorFieldsCallback = fn(
 /// Used by inventory to store references to static functions for editor components.
 pub struct KissingComponent {
 	kissing_component_data: fn() -> KissingComponentData,
+	cached_data: OnceLock<KissingComponentData>,
 	add_component_from_editor_fields: AddComponentFromEditorFieldsCallback,
+	component_to_dictionary: Option<ComponentToDictionaryCallback>,
+	has_component: Option<ComponentOnEntityCallback>,
+	remove_component: Option<ComponentOnEntityCallback>,
+	migrate_editor_fields: Option<MigrateEditorFieldsCallback>,
+	register_hooks: Option<RegisterHooksCallback>,
 }
 
 impl KissingComponent {
@@ -67,18 +109,142 @@ impl KissingComponent {
 	) -> Self {
 		Self {
 			kissing_component_data,
+			cached_data: OnceLock::new(),
 			add_component_from_editor_fields,
+			component_to_dictionary: None,
+			has_component: None,
+			remove_component: None,
+			migrate_editor_fields: None,
+			register_hooks: None,
+		}
+	}
+
+	/// Enables reading, checking for, and removing the component `T` at runtime.
+	pub const fn with_accessors<T: Component>(
+		mut self,
+		component_to_dictionary: ComponentToDictionaryCallback,
+	) -> Self {
+		self.component_to_dictionary = Some(component_to_dictionary);
+		self.has_component = Some(has_component::<T>);
+		self.remove_component = Some(remove_component::<T>);
+		self
+	}
+
+	/// Sets the function used to upgrade stored editor fields from older versions.
+	pub const fn with_migration(mut self, migrate_editor_fields: MigrateEditorFieldsCallback) -> Self {
+		self.migrate_editor_fields = Some(migrate_editor_fields);
+		self
+	}
+
+	/// Registers the [`GodotResourceHolder`] hooks of `T` when the app is built, so its
+	/// resources are retained while it's on an entity.
+	pub const fn with_godot_resource_holder<T: GodotResourceHolder>(mut self) -> Self {
+		self.register_hooks = Some(register_godot_resource_hooks::<T>);
+		self
+	}
+
+	/// Registers the component's hooks, if it has any. Must run before the component is
+	/// added to any entity.
+	pub(crate) fn register_hooks(&self, world: &mut World) {
+		if let Some(register_hooks) = self.register_hooks {
+			register_hooks(world);
+		}
+	}
+
+	/// Upgrades `fields` saved with `from_version` so they match the component's current fields.
+	///
+	/// Fields saved under a name listed in `renamed_from` are moved to their current name first,
+	/// then the migration function runs if the stored version is older than the current one.
+	pub fn migrate_editor_fields(&self, from_version: u32, fields: &mut BTreeMap<String, Variant>) {
+		let data = self.get_data();
+		for field in &data.fields {
+			if fields.contains_key(field.name) {
+				continue;
+			}
+			let old_value = field
+				.renamed_from
+				.iter()
+				.find_map(|old_name| fields.remove(*old_name));
+			if let Some(old_value) = old_value {
+				fields.insert(field.name.to_string(), old_value);
+			}
+		}
+
+		if from_version < data.version {
+			if let Some(migrate_editor_fields) = self.migrate_editor_fields {
+				migrate_editor_fields(from_version, fields);
+			}
+		}
+	}
+
+	/// Constrains `fields` to the hints of the component's fields, such as clamping ranges,
+	/// so values edited by hand or saved with older hints are still valid.
+	pub fn constrain_editor_fields(&self, fields: &mut BTreeMap<String, Variant>) {
+		let data = self.get_data();
+		for field in &data.fields {
+			if let Some(value) = fields.remove(field.name) {
+				let value = field.hint.constrain(value, field.name);
+				fields.insert(field.name.to_string(), value);
+			}
+		}
+		for variant in &data.variants {
+			for field in &variant.fields {
+				let name = enum_component_field_name(variant.name, field.name);
+				if let Some(value) = fields.remove(&name) {
+					let value = field.hint.constrain(value, &name);
+					fields.insert(name, value);
+				}
+			}
 		}
 	}
 
 	pub fn get_add_component_from_editor_fields(&self) -> &AddComponentFromEditorFieldsCallback {
 		&self.add_component_from_editor_fields
 	}
+
+	pub fn get_component_to_dictionary(&self) -> Option<&ComponentToDictionaryCallback> {
+		self.component_to_dictionary.as_ref()
+	}
+
+	pub fn get_has_component(&self) -> Option<&ComponentOnEntityCallback> {
+		self.has_component.as_ref()
+	}
+
+	pub fn get_remove_component(&self) -> Option<&ComponentOnEntityCallback> {
+		self.remove_component.as_ref()
+	}
 }
 
 impl GetData for KissingComponent {
 	type Data = KissingComponentData;
-	fn get_data(&self) -> KissingComponentData {
-		(self.kissing_component_data)()
+	fn get_data(&self) -> &KissingComponentData {
+		self.cached_data.get_or_init(self.kissing_component_data)
 	}
 }
+
+// -------------
+// * Functions *
+// -------------
+
+/// Registers the hooks of a [`GodotResourceHolder`], unless `T` already has its own hooks.
+fn register_godot_resource_hooks<T: GodotResourceHolder>(world: &mut World) {
+	let hooks = world.register_component_hooks::<T>();
+	hooks.try_on_insert(retain_godot_resources::<T>);
+	hooks.try_on_replace(release_godot_resources::<T>);
+}
+
+/// Returns `true` if `entity` has the component `T`.
+fn has_component<T: Component>(world: &mut World, entity: Entity) -> bool {
+	world
+		.get_entity(entity)
+		.is_ok_and(|entity| entity.contains::<T>())
+}
+
+/// Removes the component `T` from `entity`, returning `true` if it was present.
+fn remove_component<T: Component>(world: &mut World, entity: Entity) -> bool {
+	world
+		.get_entity_mut(entity)
+		.ok()
+		.and_then(|mut entity| entity.take::<T>())
+		.is_some()
+}
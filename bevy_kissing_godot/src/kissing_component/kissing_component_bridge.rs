@@ -1,6 +1,10 @@
-use crate::kissing_component::kissing_component::COMPONENT_NAME_TO_FUNC;
+use crate::kissing_component::kissing_component::{
+	COMPONENT_NAME_TO_KISSING_COMPONENT, KissingComponent,
+};
+use crate::kissing_component::kissing_component_error::KissingComponentError;
+use crate::kissing_registry::GetData;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 
 use bevy::prelude::*;
@@ -30,17 +34,115 @@ impl Display for ConvertComponentDataVariantToRustError {
 	}
 }
 
+/// An error that occurs while accessing a "kissing" component at runtime.
+pub(crate) enum KissingComponentAccessError {
+	ComponentNotFound(StringName),
+	AccessorsNotGenerated(StringName),
+	EntityLacksComponent(StringName),
+	FieldsRejected(StringName, KissingComponentError),
+}
+
+impl Display for KissingComponentAccessError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::ComponentNotFound(name) => {
+				write!(f, "could not find Bevy Component of name {}", name)
+			}
+			Self::AccessorsNotGenerated(name) => {
+				write!(f, "Bevy Component {} does not support runtime access", name)
+			}
+			Self::EntityLacksComponent(name) => {
+				write!(f, "entity does not have Bevy Component {}", name)
+			}
+			Self::FieldsRejected(name, e) => {
+				write!(f, "fields could not be applied to Bevy Component {} ({})", name, e)
+			}
+		}
+	}
+}
+
+// -----------
+// * Structs *
+// -----------
+
+/// One entry of the "bevy_components" metadata, or of the overrides metadata of any level.
+struct ComponentDataEntry {
+	name: StringName,
+	version: u32,
+	fields: BTreeMap<String, Variant>,
+
+	/// Only used by overrides, to remove a component added by the base scene.
+	removed: bool,
+}
+
 // -------------
 // * Functions *
 // -------------
 
 /// Applies the "kissing" components defined in the Godot editor on a node.
+///
+/// If the node comes from instanced scenes, the overrides of each level of nesting
+/// ("bevy_component_overrides", then "bevy_component_overrides_2", etc.) are merged on top
+/// of the "bevy_components" of the base scene, before the fields are migrated.
 pub fn apply_kissing_components<'a>(node: &mut Gd<Node>, world: &mut World, entity: Entity) {
-	if !node.has_meta("bevy_components") {
+	let override_meta_names = get_override_meta_names(node);
+	if !node.has_meta("bevy_components") && override_meta_names.is_empty() {
 		return;
 	}
 
-	let component_data = node.get_meta("bevy_components");
+	let mut entries = read_component_entries(node, "bevy_components");
+	for meta_name in override_meta_names {
+		let overrides = read_component_entries(node, &meta_name);
+		merge_component_overrides(&mut entries, overrides);
+	}
+
+	for (kissing_component, mut entry) in order_required_components_first(entries) {
+		kissing_component.migrate_editor_fields(entry.version, &mut entry.fields);
+		kissing_component.constrain_editor_fields(&mut entry.fields);
+		if let Err(e) = (kissing_component.get_add_component_from_editor_fields())(
+			node,
+			world,
+			&entity,
+			entry.fields,
+		) {
+			godot_error!("Could not add Bevy Component {} to {} (reason: {}).", entry.name, node, e);
+		}
+	}
+}
+
+/// Returns the names of the overrides metadata on `node`, ordered from the innermost level of
+/// instanced scenes to the outermost, so outer scenes override inner ones.
+fn get_override_meta_names(node: &Gd<Node>) -> Vec<String> {
+	const OVERRIDES_META_NAME: &str = "bevy_component_overrides";
+
+	let mut levels = node
+		.get_meta_list()
+		.iter_shared()
+		.filter_map(|name| {
+			let name = name.to_string();
+			let level = match name.strip_prefix(OVERRIDES_META_NAME)? {
+				"" => 1,
+				level => level.strip_prefix('_')?.parse::<usize>().ok()?,
+			};
+			Some((level, name))
+		})
+		.collect::<Vec<(usize, String)>>();
+	levels.sort_by_key(|(level, _)| *level);
+	levels.into_iter().map(|(_, name)| name).collect()
+}
+
+/// Reads the component entries stored in the metadata `meta_name` of `node`.
+///
+/// The fields aren't migrated yet, so migrations see the fields merged from every level.
+fn read_component_entries(
+	node: &Gd<Node>,
+	meta_name: &str,
+) -> Vec<(&'static KissingComponent, ComponentDataEntry)> {
+	if !node.has_meta(meta_name) {
+		return vec![];
+	}
+
+	let component_data = node.get_meta(meta_name);
 	let d = match convert_component_data_variant_to_rust(component_data) {
 		Ok(d) => d,
 		Err(e) => {
@@ -49,24 +151,297 @@ pub fn apply_kissing_components<'a>(node: &mut Gd<Node>, world: &mut World, enti
 				node,
 				e
 			);
+			return vec![];
+		}
+	};
+
+	d.into_iter()
+		.filter_map(|entry| match COMPONENT_NAME_TO_KISSING_COMPONENT.get(&entry.name) {
+			Some(kissing_component) => Some((*kissing_component, entry)),
+			None => {
+				godot_error!("Could not find Bevy Component of name {}.", entry.name);
+				None
+			}
+		})
+		.collect()
+}
+
+/// Merges the entries of one level of an instanced node's overrides on top of the entries
+/// of the levels below it.
+///
+/// Overridden fields replace the base fields of the same component, and fields that aren't
+/// overridden keep their base value. Override entries marked as "removed" remove the base
+/// component, and entries for components not in the base scene are added.
+fn merge_component_overrides(
+	entries: &mut Vec<(&'static KissingComponent, ComponentDataEntry)>,
+	overrides: Vec<(&'static KissingComponent, ComponentDataEntry)>,
+) {
+	for (kissing_component, mut entry) in overrides {
+		let base = entries
+			.iter()
+			.position(|(c, _)| std::ptr::eq(*c, kissing_component));
+		match base {
+			Some(index) if entry.removed => {
+				entries.remove(index);
+			}
+			Some(index) => {
+				let base_entry = &mut entries[index].1;
+				if base_entry.version != entry.version {
+					// The fields were saved with different versions, so they can't share one
+					// migration. Both are brought to the current version before merging.
+					kissing_component.migrate_editor_fields(base_entry.version, &mut base_entry.fields);
+					kissing_component.migrate_editor_fields(entry.version, &mut entry.fields);
+					base_entry.version = kissing_component.get_data().version;
+				}
+				base_entry.fields.extend(entry.fields);
+			}
+			None if entry.removed => {}
+			None => entries.push((kissing_component, entry)),
+		}
+	}
+}
+
+/// Orders `entries` so components required by another entry are added before it.
+///
+/// Otherwise Bevy's `#[require]` would insert the required component with its default value
+/// first, and its hooks and observers would see that instead of the value set in the editor.
+/// Components that require each other keep their stored order, and a warning is printed.
+fn order_required_components_first(
+	entries: Vec<(&'static KissingComponent, ComponentDataEntry)>,
+) -> Vec<(&'static KissingComponent, ComponentDataEntry)> {
+	#[derive(Clone, Copy, PartialEq)]
+	enum VisitState {
+		Unvisited,
+		Visiting,
+		Visited,
+	}
+
+	fn visit(
+		index: usize,
+		entries: &[(&'static KissingComponent, ComponentDataEntry)],
+		name_to_index: &HashMap<&str, usize>,
+		states: &mut [VisitState],
+		order: &mut Vec<usize>,
+	) {
+		if states[index] != VisitState::Unvisited {
 			return;
 		}
+		states[index] = VisitState::Visiting;
+		let data = entries[index].0.get_data();
+		for required in data.requires {
+			let Some(&required_index) = name_to_index.get(required) else {
+				continue;
+			};
+			if states[required_index] == VisitState::Visiting {
+				godot_warn!(
+					"Bevy Components {} and {} require each other, so {} may be added with its default value first.",
+					data.name,
+					required,
+					required
+				);
+				continue;
+			}
+			visit(required_index, entries, name_to_index, states, order);
+		}
+		states[index] = VisitState::Visited;
+		order.push(index);
+	}
+
+	let name_to_index = entries
+		.iter()
+		.enumerate()
+		.map(|(index, (c, _))| (c.get_data().name, index))
+		.collect::<HashMap<&str, usize>>();
+	let mut states = vec![VisitState::Unvisited; entries.len()];
+	let mut order = Vec::with_capacity(entries.len());
+	for index in 0..entries.len() {
+		visit(index, &entries, &name_to_index, &mut states, &mut order);
+	}
+
+	let mut entries = entries.into_iter().map(Some).collect::<Vec<_>>();
+	order
+		.into_iter()
+		.filter_map(|index| entries[index].take())
+		.collect()
+}
+
+/// Converts the "kissing" component named `name` on `entity` to a dictionary using the
+/// same format as the "bevy_components" metadata's "data".
+pub(crate) fn get_component(
+	world: &World,
+	entity: Entity,
+	name: &StringName,
+) -> Result<VarDictionary, KissingComponentAccessError> {
+	let kissing_component = find_kissing_component(name)?;
+	let Some(to_dictionary) = kissing_component.get_component_to_dictionary() else {
+		return Err(KissingComponentAccessError::AccessorsNotGenerated(name.clone()));
 	};
+	to_dictionary(world, entity)
+		.ok_or_else(|| KissingComponentAccessError::EntityLacksComponent(name.clone()))
+}
 
-	for entry in d {
-		let Some(func) = COMPONENT_NAME_TO_FUNC.get(&entry.0) else {
-			godot_error!("Could not find Bevy Component of name {}.", entry.0);
-			continue;
-		};
-		func(node, world, &entity, entry.1);
+/// Inserts (or replaces) the "kissing" component named `name` on `entity` using `data`.
+///
+/// `data` uses the same format as the "bevy_components" metadata's "data".
+pub(crate) fn set_component(
+	node: &mut Gd<Node>,
+	world: &mut World,
+	entity: Entity,
+	name: &StringName,
+	data: VarDictionary,
+) -> Result<(), KissingComponentAccessError> {
+	let kissing_component = find_kissing_component(name)?;
+	let mut fields = convert_dictionary_to_string_string_map(data);
+	kissing_component.migrate_editor_fields(kissing_component.get_data().version, &mut fields);
+	kissing_component.constrain_editor_fields(&mut fields);
+	(kissing_component.get_add_component_from_editor_fields())(node, world, &entity, fields)
+		.map_err(|e| KissingComponentAccessError::FieldsRejected(name.clone(), e))
+}
+
+/// Upgrades `data` saved with `version` for the "kissing" component named `name`, the same
+/// way it's upgraded before the component is added.
+///
+/// `data` uses the same format as the "bevy_components" metadata's "data".
+pub(crate) fn migrate_component_data(
+	name: &StringName,
+	version: u32,
+	data: VarDictionary,
+) -> Result<VarDictionary, KissingComponentAccessError> {
+	let kissing_component = find_kissing_component(name)?;
+	let mut fields = convert_dictionary_to_string_string_map(data);
+	kissing_component.migrate_editor_fields(version, &mut fields);
+
+	let mut result = VarDictionary::new();
+	for (key, value) in fields {
+		result.set(key, &value);
+	}
+	Ok(result)
+}
+
+/// Returns `true` if `entity` has the "kissing" component named `name`.
+pub(crate) fn has_component(
+	world: &mut World,
+	entity: Entity,
+	name: &StringName,
+) -> Result<bool, KissingComponentAccessError> {
+	let kissing_component = find_kissing_component(name)?;
+	let Some(has_component) = kissing_component.get_has_component() else {
+		return Err(KissingComponentAccessError::AccessorsNotGenerated(name.clone()));
+	};
+	Ok(has_component(world, entity))
+}
+
+/// Removes the "kissing" component named `name` from `entity`, returning `true` if it was present.
+pub(crate) fn remove_component(
+	world: &mut World,
+	entity: Entity,
+	name: &StringName,
+) -> Result<bool, KissingComponentAccessError> {
+	let kissing_component = find_kissing_component(name)?;
+	let Some(remove_component) = kissing_component.get_remove_component() else {
+		return Err(KissingComponentAccessError::AccessorsNotGenerated(name.clone()));
+	};
+	Ok(remove_component(world, entity))
+}
+
+/// Writes the "kissing" components of `entity` back into the "bevy_components" metadata
+/// of `node`, using the same format [`apply_kissing_components`] reads.
+///
+/// If `names` is `None`, every "kissing" component on `entity` that supports runtime access
+/// is written. Existing entries for the written components are replaced in place, and
+/// entries for any other components are kept.
+pub(crate) fn persist_components(
+	node: &mut Gd<Node>,
+	world: &World,
+	entity: Entity,
+	names: Option<&[StringName]>,
+) -> Result<(), KissingComponentAccessError> {
+	let mut persisted = vec![];
+	match names {
+		Some(names) => {
+			for name in names {
+				let kissing_component = find_kissing_component(name)?;
+				let data = get_component(world, entity, name)?;
+				persisted.push((kissing_component, data));
+			}
+		}
+		None => {
+			for kissing_component in inventory::iter::<KissingComponent>() {
+				let Some(to_dictionary) = kissing_component.get_component_to_dictionary() else {
+					continue;
+				};
+				if let Some(data) = to_dictionary(world, entity) {
+					persisted.push((kissing_component, data));
+				}
+			}
+		}
+	}
+
+	let existing = if node.has_meta("bevy_components") {
+		node.get_meta("bevy_components")
+			.try_to::<Array<Variant>>()
+			.unwrap_or_default()
+	} else {
+		Array::new()
+	};
+
+	let to_entry = |kissing_component: &KissingComponent, data: &VarDictionary| {
+		let component_data = kissing_component.get_data();
+		vdict! {
+			"name" => component_data.name,
+			"version" => component_data.version,
+			"data" => data,
+		}
+		.to_variant()
+	};
+
+	let mut written = vec![false; persisted.len()];
+	let mut result = Array::<Variant>::new();
+	for entry in existing.iter_shared() {
+		let index = entry
+			.try_to::<VarDictionary>()
+			.ok()
+			.and_then(|d| d.get("name"))
+			.and_then(|n| {
+				n.try_to::<StringName>()
+					.ok()
+					.or_else(|| n.try_to::<String>().ok().map(|n| StringName::from(&n)))
+			})
+			.and_then(|n| COMPONENT_NAME_TO_KISSING_COMPONENT.get(&n).copied())
+			.and_then(|c| persisted.iter().position(|(p, _)| std::ptr::eq(*p, c)));
+		match index {
+			Some(index) if written[index] => {}
+			Some(index) => {
+				written[index] = true;
+				result.push(&to_entry(persisted[index].0, &persisted[index].1));
+			}
+			None => result.push(&entry),
+		}
+	}
+	for (index, (kissing_component, data)) in persisted.iter().enumerate() {
+		if !written[index] {
+			result.push(&to_entry(kissing_component, data));
+		}
 	}
+
+	node.set_meta("bevy_components", &result.to_variant());
+	Ok(())
+}
+
+fn find_kissing_component(
+	name: &StringName,
+) -> Result<&'static KissingComponent, KissingComponentAccessError> {
+	COMPONENT_NAME_TO_KISSING_COMPONENT
+		.get(name)
+		.copied()
+		.ok_or_else(|| KissingComponentAccessError::ComponentNotFound(name.clone()))
 }
 
 /// Converts the "bevy_components" metadata from a `Node`, to a
 /// Rust-digestable representation.
 fn convert_component_data_variant_to_rust(
 	variant: Variant,
-) -> Result<Vec<(StringName, BTreeMap<String, Variant>)>, ConvertComponentDataVariantToRustError> {
+) -> Result<Vec<ComponentDataEntry>, ConvertComponentDataVariantToRustError> {
 	let mut result = vec![];
 
 	let Ok(component_data) = variant.try_to::<Array<Variant>>() else {
@@ -99,7 +474,23 @@ fn convert_component_data_variant_to_rust(
 			return Err(ConvertComponentDataVariantToRustError::EntryNameInvalid);
 		}
 
-		result.push((name, convert_dictionary_to_string_string_map(data)));
+		// Metadata saved before components were versioned counts as version 0.
+		let version = component
+			.get("version")
+			.and_then(|v| v.try_to::<u32>().ok())
+			.unwrap_or_default();
+
+		let removed = component
+			.get("removed")
+			.and_then(|r| r.try_to::<bool>().ok())
+			.unwrap_or_default();
+
+		result.push(ComponentDataEntry {
+			name,
+			version,
+			fields: convert_dictionary_to_string_string_map(data),
+			removed,
+		});
 	}
 
 	Ok(result)
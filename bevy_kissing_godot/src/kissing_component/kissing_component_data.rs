@@ -1,13 +1,50 @@
 use godot::prelude::*;
 
-use crate::kissing_registry::ToGodotDictionary;
+use crate::{
+	kissing_component::kissing_component_field_hint::KissingComponentFieldHint,
+	kissing_registry::ToGodotDictionary,
+};
+
+// -------------
+// * Constants *
+// -------------
+
+/// The editor field that stores the selected variant of an enum component.
+pub const ENUM_COMPONENT_VARIANT_FIELD: &str = "variant";
+
+/// The editor field that stores the only field of a newtype component like `struct Speed(f32)`.
+pub const NEWTYPE_COMPONENT_FIELD: &str = "value";
+
+// -----------
+// * Structs *
+// -----------
 
 /// A structure containing the data for a "kissing" component.
+#[derive(Default)]
 pub struct KissingComponentData {
 	pub name: &'static str,
 	pub data_class_name: &'static str,
 	pub docs: &'static str,
 	pub fields: Vec<KissingComponentFieldData>,
+
+	/// The variants of an enum component. Empty for struct components.
+	///
+	/// The selected variant is stored in [`ENUM_COMPONENT_VARIANT_FIELD`], and each variant's
+	/// fields are stored as `"Variant/field"`.
+	pub variants: Vec<KissingComponentVariantData>,
+
+	/// Previous names of this component, from `#[kissing_component(aliases = [...])]`.
+	/// Stored metadata that uses one of these names still resolves to this component.
+	pub aliases: &'static [&'static str],
+
+	/// The schema version of this component's editor fields. Stored metadata with a lower
+	/// version is passed through the component's migration before the component is built.
+	pub version: u32,
+
+	/// The components this component requires, from Bevy's `#[require(...)]` and
+	/// `#[kissing_component(requires(...))]`. Bevy inserts them with their default values
+	/// unless the node sets them in the editor.
+	pub requires: &'static [&'static str],
 }
 
 impl ToGodotDictionary for KissingComponentData {
@@ -16,6 +53,38 @@ impl ToGodotDictionary for KissingComponentData {
 			"name" => self.name,
 			"data_class_name" => self.data_class_name,
 			"docs" => self.docs,
+			"aliases" => &self.aliases
+				.iter()
+				.map(|a| GString::from(*a))
+				.collect::<PackedStringArray>(),
+			"version" => self.version,
+			"requires" => &self.requires
+				.iter()
+				.map(|r| GString::from(*r))
+				.collect::<PackedStringArray>(),
+			"fields" => &self.fields
+				.iter()
+				.map(|s| s.to_dictionary())
+				.collect::<Array<VarDictionary>>(),
+			"variants" => &self.variants
+				.iter()
+				.map(|v| v.to_dictionary())
+				.collect::<Array<VarDictionary>>(),
+		}
+	}
+}
+
+/// A structure containing the data for a variant of an enum "kissing" component.
+#[derive(Default)]
+pub struct KissingComponentVariantData {
+	pub name: &'static str,
+	pub fields: Vec<KissingComponentFieldData>,
+}
+
+impl KissingComponentVariantData {
+	pub fn to_dictionary(&self) -> VarDictionary {
+		vdict! {
+			"name" => self.name,
 			"fields" => &self.fields
 				.iter()
 				.map(|s| s.to_dictionary())
@@ -25,10 +94,17 @@ impl ToGodotDictionary for KissingComponentData {
 }
 
 /// A structure containing the data for a "kissing" component's fields.
+#[derive(Default)]
 pub struct KissingComponentFieldData {
 	pub name: &'static str,
 	pub type_string: &'static str,
 	pub description: Option<&'static str>,
+
+	/// Previous names of this field, from `#[export(rename_from = "old")]`.
+	pub renamed_from: &'static [&'static str],
+
+	/// The editor hint from `#[export(range = ...)]`, `#[export(file = ...)]`, etc.
+	pub hint: KissingComponentFieldHint,
 }
 
 impl KissingComponentFieldData {
@@ -37,6 +113,31 @@ impl KissingComponentFieldData {
 			"name" => self.name,
 			"type_string" => self.type_string,
 			"description" => self.description.unwrap_or_default(),
+			"renamed_from" => &self.renamed_from
+				.iter()
+				.map(|n| GString::from(*n))
+				.collect::<PackedStringArray>(),
+			"hint" => self.hint.property_hint().ord(),
+			"hint_string" => self.hint.hint_string(),
 		}
 	}
 }
+
+// -------------
+// * Functions *
+// -------------
+
+/// Returns the editor field name for the field at `index` of a tuple-struct component with
+/// `field_count` fields. Newtype components use [`NEWTYPE_COMPONENT_FIELD`], others use `"_0"`, `"_1"`, etc.
+pub fn tuple_component_field_name(index: usize, field_count: usize) -> String {
+	if field_count == 1 {
+		NEWTYPE_COMPONENT_FIELD.to_string()
+	} else {
+		format!("_{}", index)
+	}
+}
+
+/// Returns the editor field name for `field` of the enum component variant `variant`.
+pub fn enum_component_field_name(variant: &str, field: &str) -> String {
+	format!("{}/{}", variant, field)
+}
@@ -0,0 +1,107 @@
+use crate::kissing_component::{
+	kissing_component_data::KissingComponentData,
+	kissing_component_field_hint::KissingComponentFieldHint,
+	kissing_component_group::{KissingComponentGroup, group_field_name},
+};
+
+use godot::{
+	global::{PropertyHint, PropertyUsageFlags},
+	meta::{ClassId, PropertyHintInfo, PropertyInfo},
+	prelude::*,
+};
+
+// -----------
+// * Structs *
+// -----------
+
+/// The properties of a generated data object's `#[export_group]` fields and enum component
+/// variant fields.
+///
+/// They're named `"group/field"` or `"Variant/field"`, so they can't be struct fields of the
+/// data object and are provided through `get_property_list` instead.
+/// `pub` since used in "user code" generated via macro code.
+#[derive(Default)]
+pub struct DataObjectGroupProperties {
+	properties: Vec<PropertyInfo>,
+	values: Vec<(StringName, Variant)>,
+}
+
+impl DataObjectGroupProperties {
+	/// Adds a property for each field of the group `T` named `prefix`, set to its default value.
+	pub fn with_group<T: KissingComponentGroup>(mut self, prefix: &str) -> Self {
+		let mut defaults = VarDictionary::new();
+		T::default().to_editor_fields(prefix, &mut defaults);
+		for field in T::group_fields() {
+			let name = group_field_name(prefix, field.name);
+			let value = defaults.get(name.as_str()).unwrap_or_default();
+			self = self.with_property(&name, value, field.hint);
+		}
+		self
+	}
+
+	/// Adds the property `name` set to `value`. Its type is the type of `value`.
+	pub fn with_property(
+		mut self,
+		name: &str,
+		value: Variant,
+		hint: KissingComponentFieldHint,
+	) -> Self {
+		let name = StringName::from(name);
+		self.properties.push(PropertyInfo {
+			variant_type: value.get_type(),
+			class_id: ClassId::none(),
+			property_name: name.clone(),
+			hint_info: PropertyHintInfo {
+				hint: hint.property_hint(),
+				hint_string: GString::from(&hint.hint_string()),
+			},
+			usage: PropertyUsageFlags::DEFAULT,
+		});
+		self.values.push((name, value));
+		self
+	}
+
+	pub fn get(&self, property: &StringName) -> Option<Variant> {
+		self.values
+			.iter()
+			.find(|(name, _)| name == property)
+			.map(|(_, value)| value.clone())
+	}
+
+	/// Returns `false` if `property` isn't one of the group properties.
+	pub fn set(&mut self, property: &StringName, value: Variant) -> bool {
+		let Some((_, stored)) = self.values.iter_mut().find(|(name, _)| name == property) else {
+			return false;
+		};
+		*stored = value;
+		true
+	}
+
+	pub fn property_list(&self) -> Vec<PropertyInfo> {
+		self.properties.clone()
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Applies the editor hint of the field that `property` belongs to, so hints that aren't
+/// known when the data object is generated (like an exported enum's variant names) show up
+/// in the component editor. Hints that gdext already set from `#[export(...)]` are kept.
+/// `pub` since used in "user code" generated via macro code.
+pub fn validate_data_object_property(data: &KissingComponentData, property: &mut PropertyInfo) {
+	let name = property.property_name.to_string();
+	let Some(field) = data.fields.iter().find(|f| f.name == name) else {
+		return;
+	};
+	if matches!(field.hint, KissingComponentFieldHint::None)
+		|| property.hint_info.hint != PropertyHint::NONE
+	{
+		return;
+	}
+	property.hint_info = PropertyHintInfo {
+		hint: field.hint.property_hint(),
+		hint_string: GString::from(&field.hint.hint_string()),
+	};
+}
@@ -0,0 +1,65 @@
+use godot::{global::PropertyHint, meta::PropertyHintInfo, prelude::*};
+
+// ----------
+// * Traits *
+// ----------
+
+/// A trait that allows a fieldless enum to be `#[export]`ed in a "kissing" component.
+///
+/// The editor shows the variants as a dropdown and stores the selected variant's name, so
+/// reordering variants does not change the meaning of saved scenes.
+pub trait KissingComponentEnum: Sized + Default {
+	/// The names of all variants, in declaration order.
+	const VARIANT_NAMES: &'static [&'static str];
+
+	/// Returns the variant with the name `name`, or `None` if no variant has that name.
+	fn from_variant_name(name: &str) -> Option<Self>;
+
+	/// Returns the name of this variant.
+	fn variant_name(&self) -> &'static str;
+
+	/// The property hint used to display the variants as a dropdown in the editor.
+	fn property_hint() -> PropertyHintInfo {
+		PropertyHintInfo {
+			hint: PropertyHint::ENUM,
+			hint_string: GString::from(&Self::VARIANT_NAMES.join(",")),
+		}
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Reconstructs an enum field from the value stored in the editor metadata.
+///
+/// The value is expected to be the variant's name. Integers are accepted as variant
+/// indexes for fields that used to be exported as numbers. If the variant was renamed
+/// or removed, a warning is printed and `T::default()` is used.
+/// `pub` since used in "user code" generated via macro code.
+pub fn enum_from_editor_field<T: KissingComponentEnum>(value: &Variant) -> T {
+	let name = if let Ok(name) = value.try_to::<String>() {
+		name
+	} else if let Ok(index) = value.try_to::<i64>() {
+		usize::try_from(index)
+			.ok()
+			.and_then(|i| T::VARIANT_NAMES.get(i))
+			.map(|n| n.to_string())
+			.unwrap_or_default()
+	} else {
+		String::new()
+	};
+
+	if let Some(variant) = T::from_variant_name(&name) {
+		return variant;
+	}
+
+	let default = T::default();
+	godot_warn!(
+		"{} has no variant named \"{}\", using {} instead.",
+		std::any::type_name::<T>(),
+		name,
+		default.variant_name()
+	);
+	default
+}
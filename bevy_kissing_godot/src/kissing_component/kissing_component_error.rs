@@ -0,0 +1,13 @@
+/// An error that occurs while building a "kissing" component from its editor fields.
+#[derive(Debug, thiserror::Error)]
+pub enum KissingComponentError {
+	#[error("entity for {node} doesn't exist")]
+	EntityDoesntExist { node: String },
+
+	#[error("{component} on {node} could not be built ({reason})")]
+	CouldNotBuild {
+		node: String,
+		component: String,
+		reason: String,
+	},
+}
@@ -1,11 +1,307 @@
+use crate::components::gd_tracker_id::{GodotNodeId, GodotResourceId};
+use crate::kissing_component::kissing_component_field_hint::KissingComponentFieldHint;
+use crate::resources::gd_tracker::{AllNodes, AllResources};
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use bevy::prelude::{Entity, World};
+
+use godot::{
+	meta::ArrayElement,
+	prelude::*,
+	register::property::{Export, Var},
+};
+
 // ----------
 // * Traits *
 // ----------
 
 /// A trait that is required for a component's property's type to be compatible
 /// in a "kissing" component.
-pub trait KissingComponentField {
-	fn parse_from_editor_input(input: &String) -> Self;
+///
+/// The field is edited in the component editor as [`KissingComponentField::EditorType`], and
+/// converted to the Rust type with [`KissingComponentField::from_editor_value`] when the
+/// component is built. Implement it on your own types to `#[export]` them:
+/// ```
+/// struct Meters(f32);
+///
+/// impl KissingComponentField for Meters {
+///     type EditorType = f32;
+///
+///     fn from_editor_value(value: f32) -> Self {
+///         Meters(value)
+///     }
+///
+///     fn to_editor_value(&self) -> f32 {
+///         self.0
+///     }
+/// }
+/// ```
+pub trait KissingComponentField: Sized {
+	/// The Godot-side type shown in the component editor.
+	type EditorType: FromGodot + ToGodot + Var + Export + Default;
+
+	/// Converts the value set in the component editor to this type.
+	fn from_editor_value(value: Self::EditorType) -> Self;
+
+	/// Converts this value to the type shown in the component editor.
+	fn to_editor_value(&self) -> Self::EditorType;
+
+	/// Parses this type from text input, falling back to the editor type's default.
+	fn parse_from_editor_input(input: &String) -> Self {
+		let value = input
+			.to_variant()
+			.try_to_relaxed::<Self::EditorType>()
+			.unwrap_or_default();
+		Self::from_editor_value(value)
+	}
+
+	/// Converts a value stored in a component's editor fields to this type.
+	/// Returns `None` if the `Variant` can't be converted.
+	///
+	/// Override this to accept values saved by older versions of the type.
+	fn from_editor_variant(value: &Variant) -> Option<Self> {
+		value
+			.try_to::<Self::EditorType>()
+			.ok()
+			.map(Self::from_editor_value)
+	}
+
+	/// The hint used for this type in the component editor, unless the field sets its own.
+	fn editor_hint() -> KissingComponentFieldHint {
+		KissingComponentFieldHint::None
+	}
+
+	/// Returns `true` if [`KissingComponentField::from_editor_value`] has to clamp `value`
+	/// because it's out of this type's range. A warning naming the field is printed if so.
+	fn clamps_editor_value(_value: &Self::EditorType) -> bool {
+		false
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Converts a value from a component's editor fields to `T`.
+/// Returns `None` if the `Variant` is not `T::EditorType`.
+/// `pub` since used in "user code" generated via macro code.
+pub fn field_from_editor_variant<T: KissingComponentField>(value: &Variant) -> Option<T> {
+	T::from_editor_variant(value)
+}
+
+/// Reads the field `field_name` of `component_name` from its editor fields.
+///
+/// If the field is missing (such as a scene saved before the field was added) or has the
+/// wrong type, a warning naming the node and component is printed and `fallback` is used,
+/// which should return the field's `#[initial_value]` or `Default`.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_editor_field<T: KissingComponentField>(
+	node: &Gd<Node>,
+	component_name: &str,
+	fields: &BTreeMap<String, Variant>,
+	field_name: &str,
+	fallback: impl FnOnce() -> T,
+) -> T {
+	let Some(value) = fields.get(field_name) else {
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} is missing, using its initial value.",
+			field_name,
+			component_name,
+			node_path_for_warning(node)
+		);
+		return fallback();
+	};
+
+	if let Ok(editor_value) = value.try_to::<T::EditorType>()
+		&& T::clamps_editor_value(&editor_value)
+	{
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} is out of range ({}), clamping it.",
+			field_name,
+			component_name,
+			node_path_for_warning(node),
+			value
+		);
+	}
+
+	match T::from_editor_variant(value) {
+		Some(value) => value,
+		None => {
+			godot_warn!(
+				"Field {} of Bevy Component {} on {} has the wrong type ({:?}), using its initial value.",
+				field_name,
+				component_name,
+				node_path_for_warning(node),
+				value.get_type()
+			);
+			fallback()
+		}
+	}
+}
+
+/// Reads the `NodePath` or resource stored in the `#[export]` node, resource or entity field
+/// `field_name` of `component_name`.
+///
+/// Unset fields (a `null` resource or an empty `NodePath`) return `None`. If the field is
+/// missing or has the wrong type, a warning naming the node and component is printed too.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_editor_reference_field<T: FromGodot>(
+	node: &Gd<Node>,
+	component_name: &str,
+	fields: &BTreeMap<String, Variant>,
+	field_name: &str,
+) -> Option<T> {
+	let Some(value) = fields.get(field_name) else {
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} is missing, leaving it unset.",
+			field_name,
+			component_name,
+			node_path_for_warning(node)
+		);
+		return None;
+	};
+	if value.is_nil() {
+		return None;
+	}
+	match value.try_to::<T>() {
+		Ok(value) => Some(value),
+		Err(_) => {
+			godot_warn!(
+				"Field {} of Bevy Component {} on {} has the wrong type ({:?}), leaving it unset.",
+				field_name,
+				component_name,
+				node_path_for_warning(node),
+				value.get_type()
+			);
+			None
+		}
+	}
+}
+
+/// Reads the array of `NodePath`s or resources stored in the `#[export]` node, resource or
+/// entity field `field_name` of `component_name`.
+///
+/// If the field is missing or has the wrong type, a warning naming the node and component is
+/// printed and the array is empty. Elements with the wrong type are skipped with a warning.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_editor_reference_array_field<T: FromGodot + ArrayElement>(
+	node: &Gd<Node>,
+	component_name: &str,
+	fields: &BTreeMap<String, Variant>,
+	field_name: &str,
+) -> Vec<T> {
+	let Some(value) = fields.get(field_name) else {
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} is missing, leaving it empty.",
+			field_name,
+			component_name,
+			node_path_for_warning(node)
+		);
+		return vec![];
+	};
+	if let Ok(typed) = value.try_to::<Array<T>>() {
+		return typed.iter_shared().collect();
+	}
+	let Ok(untyped) = value.try_to::<VarArray>() else {
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} has the wrong type ({:?}), leaving it empty.",
+			field_name,
+			component_name,
+			node_path_for_warning(node),
+			value.get_type()
+		);
+		return vec![];
+	};
+	untyped
+		.iter_shared()
+		.filter(|element| !element.is_nil())
+		.filter_map(|element| match element.try_to::<T>() {
+			Ok(element) => Some(element),
+			Err(_) => {
+				godot_warn!(
+					"Field {} of Bevy Component {} on {} has an element with the wrong type ({:?}), skipping it.",
+					field_name,
+					component_name,
+					node_path_for_warning(node),
+					element.get_type()
+				);
+				None
+			}
+		})
+		.collect()
+}
+
+/// Returns the node at `path` relative to `node` for the `#[export]` node field `field_name`
+/// of `component_name`.
+///
+/// Empty paths return `None`. If no node exists at `path`, a warning naming the node and
+/// component is printed too.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_editor_field_node(
+	node: &Gd<Node>,
+	component_name: &str,
+	field_name: &str,
+	path: &NodePath,
+) -> Option<Gd<Node>> {
+	if path.is_empty() {
+		return None;
+	}
+	let target = node.get_node_or_null(path);
+	if target.is_none() {
+		godot_warn!(
+			"Field {} of Bevy Component {} on {} points to {}, which doesn't exist, leaving it unset.",
+			field_name,
+			component_name,
+			node_path_for_warning(node),
+			path
+		);
+	}
+	target
+}
+
+/// Returns the path of `node` if it's in the tree, otherwise its name.
+pub(crate) fn node_path_for_warning(node: &Gd<Node>) -> String {
+	if node.is_inside_tree() {
+		node.get_path().to_string()
+	} else {
+		node.get_name().to_string()
+	}
+}
+
+/// Converts `value` to the `Variant` stored in a component's editor fields.
+/// `pub` since used in "user code" generated via macro code.
+pub fn field_to_editor_variant<T: KissingComponentField>(value: &T) -> Variant {
+	value.to_editor_value().to_variant()
+}
+
+/// Returns the path from the node of `entity` to the node of `id`, the format used by
+/// `#[export_node]` editor fields. Returns `None` if either node is no longer tracked.
+/// `pub` since used in "user code" generated via macro code.
+pub fn node_id_to_editor_path(world: &World, entity: Entity, id: &GodotNodeId) -> Option<NodePath> {
+	let all_nodes = world.get_non_send_resource::<AllNodes>()?;
+	let node = world
+		.get::<GodotNodeId>(entity)?
+		.try_get_as::<Node>(all_nodes)?;
+	let target = id.try_get_as::<Node>(all_nodes)?;
+	Some(node.get_path_to(&target))
+}
+
+/// Returns the path from the node of `entity` to the node of `target`, the format used by
+/// `#[export_entity]` editor fields. Returns `None` if `target` has no node.
+/// `pub` since used in "user code" generated via macro code.
+pub fn entity_to_editor_path(world: &World, entity: Entity, target: Entity) -> Option<NodePath> {
+	let id = world.get::<GodotNodeId>(target)?;
+	node_id_to_editor_path(world, entity, id)
+}
+
+/// Returns the resource of `id`, the format used by `#[export_resource]` editor fields.
+/// Returns `None` if it's no longer tracked.
+/// `pub` since used in "user code" generated via macro code.
+pub fn resource_id_to_editor_resource(world: &World, id: &GodotResourceId) -> Option<Gd<Resource>> {
+	let all_resources = world.get_non_send_resource::<AllResources>()?;
+	id.try_get_as::<Resource>(all_resources)
 }
 
 // ----------
@@ -13,6 +309,16 @@ pub trait KissingComponentField {
 // ----------
 
 impl KissingComponentField for String {
+	type EditorType = GString;
+
+	fn from_editor_value(value: GString) -> Self {
+		value.to_string()
+	}
+
+	fn to_editor_value(&self) -> GString {
+		GString::from(self)
+	}
+
 	fn parse_from_editor_input(input: &String) -> Self {
 		input.clone()
 	}
@@ -22,10 +328,51 @@ impl KissingComponentField for String {
 // * Numbers *
 // -----------
 
-macro_rules! impl_number_types {
+macro_rules! impl_godot_native_types {
+	($($ty:ty),*) => {
+		$(
+			impl KissingComponentField for $ty {
+				type EditorType = $ty;
+
+				fn from_editor_value(value: $ty) -> Self {
+					value
+				}
+
+				fn to_editor_value(&self) -> $ty {
+					*self
+				}
+
+				fn parse_from_editor_input(input: &String) -> Self {
+					input.parse::<$ty>().unwrap_or_default()
+				}
+			}
+		)*
+	};
+}
+
+impl_godot_native_types!(bool, i8, i16, i32, i64, u8, u16, u32, f32, f64);
+
+/// Number types that Godot can't represent natively are edited as `i64`.
+///
+/// Values outside the range of either type are clamped instead of wrapping.
+macro_rules! impl_wide_number_types {
 	($($ty:ty),*) => {
 		$(
 			impl KissingComponentField for $ty {
+				type EditorType = i64;
+
+				fn from_editor_value(value: i64) -> Self {
+					<$ty>::try_from(value).unwrap_or(if value < 0 { <$ty>::MIN } else { <$ty>::MAX })
+				}
+
+				fn to_editor_value(&self) -> i64 {
+					i64::try_from(*self).unwrap_or(i64::MAX)
+				}
+
+				fn clamps_editor_value(value: &i64) -> bool {
+					<$ty>::try_from(*value).is_err()
+				}
+
 				fn parse_from_editor_input(input: &String) -> Self {
 					input.parse::<$ty>().unwrap_or_default()
 				}
@@ -34,4 +381,243 @@ macro_rules! impl_number_types {
 	};
 }
 
-impl_number_types!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+impl_wide_number_types!(isize, u64, usize);
+
+// -------------------
+// * Godot Built-Ins *
+// -------------------
+
+/// Godot types are edited as themselves.
+macro_rules! impl_godot_builtin_types {
+	($($ty:ty),*) => {
+		$(
+			impl KissingComponentField for $ty {
+				type EditorType = $ty;
+
+				fn from_editor_value(value: $ty) -> Self {
+					value
+				}
+
+				fn to_editor_value(&self) -> $ty {
+					self.clone()
+				}
+			}
+		)*
+	};
+}
+
+impl_godot_builtin_types!(
+	GString,
+	StringName,
+	NodePath,
+	Vector2,
+	Vector2i,
+	Vector3,
+	Vector3i,
+	Vector4,
+	Vector4i,
+	Rect2,
+	Rect2i,
+	Plane,
+	Quaternion,
+	Basis,
+	Transform2D,
+	Transform3D,
+	Projection,
+	Aabb,
+	godot::builtin::Color,
+	Rid,
+	VarDictionary,
+	PackedByteArray,
+	PackedInt32Array,
+	PackedInt64Array,
+	PackedFloat32Array,
+	PackedFloat64Array,
+	PackedStringArray,
+	PackedVector2Array,
+	PackedVector3Array,
+	PackedVector4Array,
+	PackedColorArray
+);
+
+// ---------------
+// * Collections *
+// ---------------
+
+/// Edited as a typed Godot array of the element's editor type.
+impl<T: KissingComponentField> KissingComponentField for Vec<T>
+where
+	T::EditorType: ArrayElement,
+{
+	type EditorType = Array<T::EditorType>;
+
+	fn from_editor_value(value: Array<T::EditorType>) -> Self {
+		value.iter_shared().map(T::from_editor_value).collect()
+	}
+
+	fn to_editor_value(&self) -> Array<T::EditorType> {
+		self.iter().map(|v| v.to_editor_value()).collect()
+	}
+}
+
+/// Edited as a `Dictionary`. Entries whose key or value have the wrong type are skipped.
+impl<K: KissingComponentField + Eq + Hash, V: KissingComponentField> KissingComponentField
+	for HashMap<K, V>
+{
+	type EditorType = VarDictionary;
+
+	fn from_editor_value(value: VarDictionary) -> Self {
+		value
+			.iter_shared()
+			.filter_map(|(k, v)| {
+				Some((
+					field_from_editor_variant(&k)?,
+					field_from_editor_variant(&v)?,
+				))
+			})
+			.collect()
+	}
+
+	fn to_editor_value(&self) -> VarDictionary {
+		map_to_editor_dictionary(self.iter())
+	}
+}
+
+/// Edited as a `Dictionary`. Entries whose key or value have the wrong type are skipped.
+impl<K: KissingComponentField + Ord, V: KissingComponentField> KissingComponentField
+	for BTreeMap<K, V>
+{
+	type EditorType = VarDictionary;
+
+	fn from_editor_value(value: VarDictionary) -> Self {
+		value
+			.iter_shared()
+			.filter_map(|(k, v)| {
+				Some((
+					field_from_editor_variant(&k)?,
+					field_from_editor_variant(&v)?,
+				))
+			})
+			.collect()
+	}
+
+	fn to_editor_value(&self) -> VarDictionary {
+		map_to_editor_dictionary(self.iter())
+	}
+}
+
+fn map_to_editor_dictionary<'a, K: KissingComponentField + 'a, V: KissingComponentField + 'a>(
+	entries: impl Iterator<Item = (&'a K, &'a V)>,
+) -> VarDictionary {
+	let mut dictionary = VarDictionary::new();
+	for (k, v) in entries {
+		dictionary.set(&field_to_editor_variant(k), &field_to_editor_variant(v));
+	}
+	dictionary
+}
+
+// -------------
+// * Bevy Math *
+// -------------
+
+impl KissingComponentField for bevy::math::Vec2 {
+	type EditorType = Vector2;
+
+	fn from_editor_value(value: Vector2) -> Self {
+		Self::new(value.x as f32, value.y as f32)
+	}
+
+	fn to_editor_value(&self) -> Vector2 {
+		Vector2::new(self.x as real, self.y as real)
+	}
+}
+
+impl KissingComponentField for bevy::math::Vec3 {
+	type EditorType = Vector3;
+
+	fn from_editor_value(value: Vector3) -> Self {
+		Self::new(value.x as f32, value.y as f32, value.z as f32)
+	}
+
+	fn to_editor_value(&self) -> Vector3 {
+		Vector3::new(self.x as real, self.y as real, self.z as real)
+	}
+}
+
+impl KissingComponentField for bevy::math::IVec2 {
+	type EditorType = Vector2i;
+
+	fn from_editor_value(value: Vector2i) -> Self {
+		Self::new(value.x, value.y)
+	}
+
+	fn to_editor_value(&self) -> Vector2i {
+		Vector2i::new(self.x, self.y)
+	}
+}
+
+impl KissingComponentField for bevy::math::Quat {
+	type EditorType = Quaternion;
+
+	fn from_editor_value(value: Quaternion) -> Self {
+		Self::from_xyzw(
+			value.x as f32,
+			value.y as f32,
+			value.z as f32,
+			value.w as f32,
+		)
+	}
+
+	fn to_editor_value(&self) -> Quaternion {
+		Quaternion::new(
+			self.x as real,
+			self.y as real,
+			self.z as real,
+			self.w as real,
+		)
+	}
+}
+
+impl KissingComponentField for bevy::transform::components::Transform {
+	type EditorType = Transform3D;
+
+	fn from_editor_value(value: Transform3D) -> Self {
+		use bevy::math::{Mat4, Vec3};
+		Self::from_matrix(Mat4::from_cols(
+			Vec3::from_editor_value(value.basis.col_a()).extend(0.0),
+			Vec3::from_editor_value(value.basis.col_b()).extend(0.0),
+			Vec3::from_editor_value(value.basis.col_c()).extend(0.0),
+			Vec3::from_editor_value(value.origin).extend(1.0),
+		))
+	}
+
+	fn to_editor_value(&self) -> Transform3D {
+		let matrix = self.to_matrix();
+		Transform3D::new(
+			Basis::from_cols(
+				matrix.x_axis.truncate().to_editor_value(),
+				matrix.y_axis.truncate().to_editor_value(),
+				matrix.z_axis.truncate().to_editor_value(),
+			),
+			self.translation.to_editor_value(),
+		)
+	}
+}
+
+// --------------
+// * Bevy Color *
+// --------------
+
+#[cfg(feature = "bevy_color")]
+impl KissingComponentField for bevy::color::Color {
+	type EditorType = godot::builtin::Color;
+
+	fn from_editor_value(value: godot::builtin::Color) -> Self {
+		Self::srgba(value.r, value.g, value.b, value.a)
+	}
+
+	fn to_editor_value(&self) -> godot::builtin::Color {
+		let srgba = self.to_srgba();
+		godot::builtin::Color::from_rgba(srgba.red, srgba.green, srgba.blue, srgba.alpha)
+	}
+}
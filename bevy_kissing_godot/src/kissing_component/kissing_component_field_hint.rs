@@ -0,0 +1,185 @@
+use godot::{global::PropertyHint, prelude::*};
+
+// ---------
+// * Enums *
+// ---------
+
+/// The editor hint of an exported "kissing" component field.
+///
+/// The same hint configures the component editor and constrains the value at runtime,
+/// so values that were edited by hand or saved with older constraints are still valid.
+#[derive(Default, Clone, Copy, Debug)]
+pub enum KissingComponentFieldHint {
+	#[default]
+	None,
+
+	/// Numbers are clamped to `min..=max` and snapped to `step` if it's above zero.
+	Range { min: f64, max: f64, step: f64 },
+
+	/// An integer value of the names; unknown values fall back to the first one.
+	///
+	/// Names can set their value like `"Name:4"`, otherwise it's one more than the previous.
+	Enum(&'static [&'static str]),
+
+	/// An integer bitmask of the names; unknown bits are removed.
+	///
+	/// Names can set their value like `"Name:4"`, otherwise it's the bit of their position.
+	Flags(&'static [&'static str]),
+
+	/// A file path matching one of the filters, such as `"*.png"`.
+	File(&'static [&'static str]),
+
+	Dir,
+
+	Multiline,
+
+	/// The alpha of colors is always set to `1.0`.
+	ColorNoAlpha,
+}
+
+impl KissingComponentFieldHint {
+	/// The Godot property hint used by the component editor.
+	pub fn property_hint(&self) -> PropertyHint {
+		match self {
+			Self::None => PropertyHint::NONE,
+			Self::Range { .. } => PropertyHint::RANGE,
+			Self::Enum(_) => PropertyHint::ENUM,
+			Self::Flags(_) => PropertyHint::FLAGS,
+			Self::File(_) => PropertyHint::FILE,
+			Self::Dir => PropertyHint::DIR,
+			Self::Multiline => PropertyHint::MULTILINE_TEXT,
+			Self::ColorNoAlpha => PropertyHint::COLOR_NO_ALPHA,
+		}
+	}
+
+	/// The Godot property hint string used by the component editor.
+	pub fn hint_string(&self) -> String {
+		match self {
+			Self::Range { min, max, step } => format!("{},{},{}", min, max, step),
+			Self::Enum(names) | Self::Flags(names) | Self::File(names) => names.join(","),
+			Self::None | Self::Dir | Self::Multiline | Self::ColorNoAlpha => String::new(),
+		}
+	}
+
+	/// Returns `value` constrained to this hint.
+	///
+	/// Values that can't be fixed (like a file path with the wrong extension) are kept as
+	/// they are, and a warning naming `field_name` is printed.
+	pub fn constrain(&self, value: Variant, field_name: &str) -> Variant {
+		match self {
+			Self::Range { min, max, step } => constrain_range(value, *min, *max, *step),
+			Self::Enum(names) if !names.is_empty() && value.get_type() == VariantType::INT => {
+				let values = enum_values(names);
+				let current = value.to::<i64>();
+				if values.contains(&current) {
+					value
+				} else {
+					godot_warn!(
+						"Field {} has no enum value {}, using {} instead.",
+						field_name,
+						current,
+						values[0]
+					);
+					values[0].to_variant()
+				}
+			}
+			Self::Flags(names) if !names.is_empty() && value.get_type() == VariantType::INT => {
+				let mask = flag_values(names).fold(0, |mask, flag| mask | flag);
+				(value.to::<i64>() & mask).to_variant()
+			}
+			Self::File(filters) => {
+				if let Ok(path) = value.try_to::<String>() {
+					if !path.is_empty() && !matches_file_filters(&path, filters) {
+						godot_warn!(
+							"Field {} is set to {}, which doesn't match {}.",
+							field_name,
+							path,
+							filters.join(",")
+						);
+					}
+				}
+				value
+			}
+			Self::ColorNoAlpha => match value.try_to::<Color>() {
+				Ok(mut color) => {
+					color.a = 1.0;
+					color.to_variant()
+				}
+				Err(_) => value,
+			},
+			Self::Enum(_) | Self::Flags(_) | Self::None | Self::Dir | Self::Multiline => value,
+		}
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Clamps an integer or float `value` to `min..=max`, snapping it to `step` if above zero.
+fn constrain_range(value: Variant, min: f64, max: f64, step: f64) -> Variant {
+	// Ranges with `or_less` have no minimum, so they snap to multiples of `step` instead.
+	let base = if min.is_finite() { min } else { 0.0 };
+	let constrain = |v: f64| {
+		let v = if step > 0.0 {
+			base + ((v - base) / step).round() * step
+		} else {
+			v
+		};
+		v.clamp(min, max)
+	};
+
+	match value.get_type() {
+		VariantType::INT => (constrain(value.to::<i64>() as f64).round() as i64).to_variant(),
+		VariantType::FLOAT => constrain(value.to::<f64>()).to_variant(),
+		_ => value,
+	}
+}
+
+/// Splits an enum or flags name like `"Name:4"` into its name and explicit value.
+fn split_explicit_value(name: &str) -> (&str, Option<i64>) {
+	match name.rsplit_once(':') {
+		Some((name, value)) => match value.trim().parse::<i64>() {
+			Ok(value) => (name, Some(value)),
+			Err(_) => (name, None),
+		},
+		None => (name, None),
+	}
+}
+
+/// Returns the values of enum `names`, where names without one continue from the previous.
+fn enum_values(names: &[&str]) -> Vec<i64> {
+	let mut next = 0;
+	names
+		.iter()
+		.map(|name| {
+			let value = split_explicit_value(name).1.unwrap_or(next);
+			next = value.wrapping_add(1);
+			value
+		})
+		.collect()
+}
+
+/// Returns the values of flags `names`, where names without one use the bit of their position.
+fn flag_values<'a>(names: &'a [&'a str]) -> impl Iterator<Item = i64> + 'a {
+	names.iter().enumerate().map(|(index, name)| {
+		split_explicit_value(name)
+			.1
+			.unwrap_or_else(|| 1_i64.checked_shl(index as u32).unwrap_or(0))
+	})
+}
+
+/// Returns `true` if `path` matches any filter like `"*.png"` or `"*.png ; PNG Images"`.
+fn matches_file_filters(path: &str, filters: &[&str]) -> bool {
+	if filters.is_empty() {
+		return true;
+	}
+	let path = path.to_lowercase();
+	filters.iter().any(|filter| {
+		let pattern = filter.split(';').next().unwrap_or_default().trim().to_lowercase();
+		match pattern.strip_prefix('*') {
+			Some(suffix) => path.ends_with(suffix),
+			None => path == pattern,
+		}
+	})
+}
@@ -0,0 +1,60 @@
+use crate::kissing_component::{
+	kissing_component_data::KissingComponentFieldData,
+	kissing_component_field::{KissingComponentField, field_from_editor_variant, field_to_editor_variant},
+};
+
+use std::collections::BTreeMap;
+
+use godot::prelude::*;
+
+// ----------
+// * Traits *
+// ----------
+
+/// A trait for small structs nested in a "kissing" component with `#[export_group]`.
+///
+/// The nested struct's fields are flattened into the component's editor fields as
+/// `"group/field"`, which the Godot inspector displays as a collapsible group.
+pub trait KissingComponentGroup: Sized + Default {
+	/// The metadata of the nested fields, without the group prefix.
+	fn group_fields() -> Vec<KissingComponentFieldData>;
+
+	/// Builds the struct from the editor fields named `"{prefix}/{field}"`.
+	/// Fields that are missing or have the wrong type keep their default value.
+	fn from_editor_fields(prefix: &str, fields: &BTreeMap<String, Variant>) -> Self;
+
+	/// Writes the struct's fields into `fields` as `"{prefix}/{field}"`.
+	fn to_editor_fields(&self, prefix: &str, fields: &mut VarDictionary);
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Returns the name of the editor field for `field` in the group `prefix`.
+pub fn group_field_name(prefix: &str, field: &str) -> String {
+	format!("{}/{}", prefix, field)
+}
+
+/// Reads `field` of the group `prefix` from a component's editor fields.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_group_field<T: KissingComponentField>(
+	prefix: &str,
+	field: &str,
+	fields: &BTreeMap<String, Variant>,
+) -> Option<T> {
+	fields
+		.get(&group_field_name(prefix, field))
+		.and_then(field_from_editor_variant)
+}
+
+/// Writes `value` as `field` of the group `prefix` into a component's editor fields.
+/// `pub` since used in "user code" generated via macro code.
+pub fn set_group_field<T: KissingComponentField>(
+	prefix: &str,
+	field: &str,
+	value: &T,
+	fields: &mut VarDictionary,
+) {
+	fields.set(&group_field_name(prefix, field), &field_to_editor_variant(value));
+}
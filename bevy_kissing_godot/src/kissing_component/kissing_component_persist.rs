@@ -0,0 +1,71 @@
+use crate::kissing_component::kissing_component_bridge::persist_components;
+use crate::prelude::{AllNodes, GodotNodeId};
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+// -----------
+// * Structs *
+// -----------
+
+/// A command that writes "kissing" components of an entity back into its node's
+/// "bevy_components" metadata, so changes made at runtime are kept when the scene is saved.
+///
+/// This lets tools built with Bevy💋Godot (like level editors running in the Godot editor)
+/// save their results.
+///
+/// ```rust
+/// fn save_enemy(mut commands: Commands, enemy: Single<Entity, With<Enemy>>) {
+///     commands.queue(PersistComponents::new(*enemy, ["Enemy", "Health"]));
+/// }
+/// ```
+pub struct PersistComponents {
+	entity: Entity,
+	components: Option<Vec<String>>,
+}
+
+impl PersistComponents {
+	/// Persists the "kissing" components named `components`.
+	pub fn new(entity: Entity, components: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		Self {
+			entity,
+			components: Some(components.into_iter().map(Into::into).collect()),
+		}
+	}
+
+	/// Persists every "kissing" component on `entity` that supports runtime access.
+	pub fn all(entity: Entity) -> Self {
+		Self {
+			entity,
+			components: None,
+		}
+	}
+}
+
+impl Command for PersistComponents {
+	fn apply(self, world: &mut World) {
+		let Some(mut node) = world
+			.get::<GodotNodeId>(self.entity)
+			.copied()
+			.and_then(|id| id.try_get_as::<Node>(world.non_send_resource::<AllNodes>()))
+		else {
+			godot_error!("Could not persist Bevy Components of {}, it has no node.", self.entity);
+			return;
+		};
+
+		let names = self.components.map(|components| {
+			components
+				.iter()
+				.map(StringName::from)
+				.collect::<Vec<_>>()
+		});
+		if let Err(e) = persist_components(&mut node, world, self.entity, names.as_deref()) {
+			godot_error!(
+				"Could not persist Bevy Components of {} to {} (reason: {}).",
+				self.entity,
+				node,
+				e
+			);
+		}
+	}
+}
@@ -0,0 +1,323 @@
+use crate::kissing_component::{
+	kissing_component_data::{
+		KissingComponentData, KissingComponentFieldData, tuple_component_field_name,
+	},
+	kissing_component_error::KissingComponentError,
+	kissing_component_field::{KissingComponentField, field_from_editor_variant, field_to_editor_variant},
+};
+
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+use bevy::{
+	ecs::reflect::{AppTypeRegistry, ReflectComponent, ReflectFromWorld},
+	prelude::*,
+	reflect::{
+		DynamicStruct, DynamicTupleStruct, PartialReflect, ReflectFromReflect, ReflectRef, TypeInfo,
+		TypePath, TypeRegistry, Typed, std_traits::ReflectDefault,
+	},
+};
+use godot::prelude::*;
+
+// -------------
+// * Constants *
+// -------------
+
+/// The Godot class edited in the component dialog for components registered with
+/// [`kissing_reflect_component!`].
+pub const REFLECT_COMPONENT_DATA_CLASS_NAME: &str = "KissingReflectComponentObject";
+
+// ----------
+// * Macros *
+// ----------
+
+/// Exposes a `Component + Reflect` type to the component editor without deriving
+/// `KissingComponent`, so components from other crates can be attached in the editor.
+///
+/// The type must be registered in the `AppTypeRegistry` with `#[reflect(Component)]`, and
+/// should have `#[reflect(Default)]` so fields missing from the editor can be filled in.
+///
+/// Only fields of these types are shown in the editor: `bool`, the integer and float types,
+/// `String`, `Vec2`, `Vec3`, `IVec2`, `Quat`, `Transform` and (with the `bevy_color` feature)
+/// `Color`. Other fields, even ones implementing `KissingComponentField`, are left out and
+/// keep their `Default` value.
+///
+/// The component is named by its short type path (like `Velocity`), which must not be used
+/// by another component. If it is, an error is printed and only one of them can be used.
+///
+/// Registration is manual on purpose: the component editor lists components from the
+/// inventory while editing, when there's no running `App` or `AppTypeRegistry` to iterate.
+/// It also keeps reflected types that aren't meant for the editor out of the component list.
+///
+/// ```rust
+/// kissing_reflect_component!(other_crate::Velocity);
+/// ```
+#[macro_export]
+macro_rules! kissing_reflect_component {
+	($ty: ty) => {
+		$crate::prelude::bevy_kissing_godot_inventory::submit! {
+			$crate::kissing_component::kissing_component::KissingComponent::new(
+				$crate::kissing_component::kissing_reflect_component::reflect_component_data::<$ty>,
+				$crate::kissing_component::kissing_reflect_component::add_reflect_component_from_editor_fields::<$ty>,
+			)
+			.with_accessors::<$ty>(
+				$crate::kissing_component::kissing_reflect_component::reflect_component_to_dictionary::<$ty>,
+			)
+		}
+	};
+}
+
+// -----------
+// * Structs *
+// -----------
+
+/// A field type that reflected components can expose to the editor.
+struct ReflectFieldType {
+	type_id: TypeId,
+	short_type_path: &'static str,
+	default_editor_value: fn() -> Variant,
+	from_editor_variant: fn(&Variant) -> Option<Box<dyn PartialReflect>>,
+	to_editor_variant: fn(&dyn PartialReflect) -> Option<Variant>,
+}
+
+impl ReflectFieldType {
+	fn of<T: KissingComponentField + Reflect + TypePath>() -> Self {
+		Self {
+			type_id: TypeId::of::<T>(),
+			short_type_path: T::short_type_path(),
+			default_editor_value: || T::EditorType::default().to_variant(),
+			from_editor_variant: |value| {
+				field_from_editor_variant::<T>(value).map(|v| Box::new(v) as Box<dyn PartialReflect>)
+			},
+			to_editor_variant: |value| value.try_downcast_ref::<T>().map(field_to_editor_variant),
+		}
+	}
+}
+
+// -------------------------
+// * Top-Level Static Vars *
+// -------------------------
+
+/// The field types supported by reflected components.
+///
+/// Keep the list in the docs of [`kissing_reflect_component!`] in sync with this.
+static REFLECT_FIELD_TYPES: LazyLock<Vec<ReflectFieldType>> = LazyLock::new(|| {
+	#[cfg_attr(not(feature = "bevy_color"), allow(unused_mut))]
+	let mut field_types = vec![
+		ReflectFieldType::of::<bool>(),
+		ReflectFieldType::of::<i8>(),
+		ReflectFieldType::of::<i16>(),
+		ReflectFieldType::of::<i32>(),
+		ReflectFieldType::of::<i64>(),
+		ReflectFieldType::of::<isize>(),
+		ReflectFieldType::of::<u8>(),
+		ReflectFieldType::of::<u16>(),
+		ReflectFieldType::of::<u32>(),
+		ReflectFieldType::of::<u64>(),
+		ReflectFieldType::of::<usize>(),
+		ReflectFieldType::of::<f32>(),
+		ReflectFieldType::of::<f64>(),
+		ReflectFieldType::of::<String>(),
+		ReflectFieldType::of::<bevy::math::Vec2>(),
+		ReflectFieldType::of::<bevy::math::Vec3>(),
+		ReflectFieldType::of::<bevy::math::IVec2>(),
+		ReflectFieldType::of::<bevy::math::Quat>(),
+		ReflectFieldType::of::<bevy::transform::components::Transform>(),
+	];
+	#[cfg(feature = "bevy_color")]
+	field_types.push(ReflectFieldType::of::<bevy::color::Color>());
+	field_types
+});
+
+// -------------
+// * Functions *
+// -------------
+
+/// Builds the editor metadata of `T` from its reflected `TypeInfo`.
+/// `pub` since used in code generated by [`kissing_reflect_component!`].
+pub fn reflect_component_data<T: Typed>() -> KissingComponentData {
+	let type_info = T::type_info();
+	let fields = match type_info {
+		TypeInfo::Struct(info) => info
+			.iter()
+			.filter_map(|field| reflect_field_data(field.name(), field.type_id()))
+			.collect(),
+		TypeInfo::TupleStruct(info) => info
+			.iter()
+			.enumerate()
+			.filter_map(|(index, field)| {
+				// Leaked since the names must be `'static`. This only runs once per type, since
+				// `KissingComponent` caches its data.
+				let name = tuple_component_field_name(index, info.field_len()).leak();
+				reflect_field_data(name, field.type_id())
+			})
+			.collect(),
+		_ => vec![],
+	};
+
+	KissingComponentData {
+		name: type_info.type_path_table().short_path(),
+		data_class_name: REFLECT_COMPONENT_DATA_CLASS_NAME,
+		docs: "",
+		fields,
+		..Default::default()
+	}
+}
+
+/// Builds `T` from its editor fields and inserts it with `ReflectComponent::insert`.
+/// `pub` since used in code generated by [`kissing_reflect_component!`].
+pub fn add_reflect_component_from_editor_fields<T: Component + Typed>(
+	node: &mut Gd<Node>,
+	world: &mut World,
+	entity: &Entity,
+	fields: BTreeMap<String, Variant>,
+) -> Result<(), KissingComponentError> {
+	let could_not_build = |reason: &str| KissingComponentError::CouldNotBuild {
+		node: node.to_string(),
+		component: T::type_info().type_path_table().short_path().to_string(),
+		reason: reason.to_string(),
+	};
+
+	let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+		return Err(could_not_build("there is no AppTypeRegistry"));
+	};
+	let registry = registry.read();
+	let type_id = TypeId::of::<T>();
+	let Some(reflect_component) = registry.get_type_data::<ReflectComponent>(type_id) else {
+		return Err(could_not_build("it isn't registered with #[reflect(Component)]"));
+	};
+
+	let Some(value) = editor_fields_to_reflect(T::type_info(), &fields) else {
+		return Err(could_not_build("only structs and tuple structs can be reflected"));
+	};
+	if !can_create_from_reflect(&registry, type_id, value.as_ref()) {
+		return Err(could_not_build("some fields are missing and it lacks #[reflect(Default)]"));
+	}
+
+	let Ok(mut entity_mut) = world.get_entity_mut(*entity) else {
+		return Err(KissingComponentError::EntityDoesntExist {
+			node: node.to_string(),
+		});
+	};
+	reflect_component.insert(&mut entity_mut, value.as_ref(), &registry);
+	Ok(())
+}
+
+/// Converts the reflected `T` on `entity` to its editor fields.
+/// `pub` since used in code generated by [`kissing_reflect_component!`].
+pub fn reflect_component_to_dictionary<T: Component + Reflect>(
+	world: &World,
+	entity: Entity,
+) -> Option<VarDictionary> {
+	let component = world.get::<T>(entity)?;
+	let mut dictionary = VarDictionary::new();
+	match component.reflect_ref() {
+		ReflectRef::Struct(value) => {
+			for index in 0..value.field_len() {
+				let (Some(name), Some(field)) = (value.name_at(index), value.field_at(index)) else {
+					continue;
+				};
+				if let Some(field) = reflect_to_editor_variant(field) {
+					dictionary.set(name, &field);
+				}
+			}
+		}
+		ReflectRef::TupleStruct(value) => {
+			for (index, field) in value.iter_fields().enumerate() {
+				let name = tuple_component_field_name(index, value.field_len());
+				if let Some(field) = reflect_to_editor_variant(field) {
+					dictionary.set(name.as_str(), &field);
+				}
+			}
+		}
+		_ => {}
+	}
+	Some(dictionary)
+}
+
+/// Returns the default editor value of the reflected field type named `type_string`,
+/// or `None` if reflected components don't support it.
+pub(crate) fn reflect_field_default_editor_value(type_string: &str) -> Option<Variant> {
+	REFLECT_FIELD_TYPES
+		.iter()
+		.find(|field_type| field_type.short_type_path == type_string)
+		.map(|field_type| (field_type.default_editor_value)())
+}
+
+/// Returns the metadata of a reflected field, or `None` if its type isn't supported.
+fn reflect_field_data(name: &'static str, type_id: TypeId) -> Option<KissingComponentFieldData> {
+	let field_type = find_reflect_field_type(type_id)?;
+	Some(KissingComponentFieldData {
+		name,
+		type_string: field_type.short_type_path,
+		..Default::default()
+	})
+}
+
+fn find_reflect_field_type(type_id: TypeId) -> Option<&'static ReflectFieldType> {
+	REFLECT_FIELD_TYPES
+		.iter()
+		.find(|field_type| field_type.type_id == type_id)
+}
+
+/// Converts editor fields to a dynamic value representing `type_info`.
+///
+/// Fields that are missing or have the wrong type are left out. For tuple structs, fields
+/// after the first missing one are left out too, since they're applied by position.
+fn editor_fields_to_reflect(
+	type_info: &'static TypeInfo,
+	fields: &BTreeMap<String, Variant>,
+) -> Option<Box<dyn PartialReflect>> {
+	let from_editor_variant = |name: &str, type_id: TypeId| {
+		let field_type = find_reflect_field_type(type_id)?;
+		(field_type.from_editor_variant)(fields.get(name)?)
+	};
+
+	match type_info {
+		TypeInfo::Struct(info) => {
+			let mut value = DynamicStruct::default();
+			value.set_represented_type(Some(type_info));
+			for field in info.iter() {
+				if let Some(field_value) = from_editor_variant(field.name(), field.type_id()) {
+					value.insert_boxed(field.name(), field_value);
+				}
+			}
+			Some(Box::new(value))
+		}
+		TypeInfo::TupleStruct(info) => {
+			let mut value = DynamicTupleStruct::default();
+			value.set_represented_type(Some(type_info));
+			for (index, field) in info.iter().enumerate() {
+				let name = tuple_component_field_name(index, info.field_len());
+				let field_value = from_editor_variant(&name, field.type_id());
+				let Some(field_value) = field_value else {
+					break;
+				};
+				value.insert_boxed(field_value);
+			}
+			Some(Box::new(value))
+		}
+		_ => None,
+	}
+}
+
+/// Returns `true` if `ReflectComponent::insert` can build the type from `value`
+/// instead of panicking.
+fn can_create_from_reflect(
+	registry: &TypeRegistry,
+	type_id: TypeId,
+	value: &dyn PartialReflect,
+) -> bool {
+	registry
+		.get_type_data::<ReflectFromReflect>(type_id)
+		.is_some_and(|from_reflect| from_reflect.from_reflect(value).is_some())
+		|| registry.get_type_data::<ReflectDefault>(type_id).is_some()
+		|| registry.get_type_data::<ReflectFromWorld>(type_id).is_some()
+}
+
+fn reflect_to_editor_variant(value: &dyn PartialReflect) -> Option<Variant> {
+	REFLECT_FIELD_TYPES
+		.iter()
+		.find_map(|field_type| (field_type.to_editor_variant)(value))
+}
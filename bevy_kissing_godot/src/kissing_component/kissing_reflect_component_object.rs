@@ -0,0 +1,78 @@
+use crate::kissing_component::{
+	kissing_component::COMPONENT_NAME_TO_KISSING_COMPONENT,
+	kissing_reflect_component::reflect_field_default_editor_value,
+};
+use crate::kissing_registry::GetData;
+
+use godot::{
+	global::PropertyUsageFlags,
+	meta::{ClassId, PropertyHintInfo, PropertyInfo},
+	prelude::*,
+};
+
+/// The object edited by the component dialog for components registered with
+/// [`crate::kissing_reflect_component!`], which don't have a generated data class.
+///
+/// Its properties are created from the component's fields by `setup_component`.
+#[derive(GodotClass)]
+#[class(init, tool, base = Object)]
+pub struct KissingReflectComponentObject {
+	base: Base<Object>,
+
+	properties: Vec<PropertyInfo>,
+	values: Vec<(StringName, Variant)>,
+}
+
+#[godot_api]
+impl KissingReflectComponentObject {
+	/// Creates a property for each field of the component named `component_name`.
+	#[func]
+	fn setup_component(&mut self, component_name: StringName) {
+		let Some(kissing_component) = COMPONENT_NAME_TO_KISSING_COMPONENT.get(&component_name) else {
+			godot_error!("Could not find Bevy Component of name {}.", component_name);
+			return;
+		};
+
+		self.properties.clear();
+		self.values.clear();
+		for field in &kissing_component.get_data().fields {
+			let Some(default_value) = reflect_field_default_editor_value(field.type_string) else {
+				continue;
+			};
+			let name = StringName::from(field.name);
+			self.properties.push(PropertyInfo {
+				variant_type: default_value.get_type(),
+				class_id: ClassId::none(),
+				property_name: name.clone(),
+				hint_info: PropertyHintInfo {
+					hint: field.hint.property_hint(),
+					hint_string: GString::from(&field.hint.hint_string()),
+				},
+				usage: PropertyUsageFlags::DEFAULT,
+			});
+			self.values.push((name, default_value));
+		}
+	}
+}
+
+#[godot_api]
+impl IObject for KissingReflectComponentObject {
+	fn get_property(&self, property: StringName) -> Option<Variant> {
+		self.values
+			.iter()
+			.find(|(name, _)| *name == property)
+			.map(|(_, value)| value.clone())
+	}
+
+	fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+		let Some((_, stored)) = self.values.iter_mut().find(|(name, _)| *name == property) else {
+			return false;
+		};
+		*stored = value;
+		true
+	}
+
+	fn get_property_list(&mut self) -> Vec<PropertyInfo> {
+		self.properties.clone()
+	}
+}
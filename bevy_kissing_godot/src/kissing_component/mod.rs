@@ -1,4 +1,12 @@
 pub mod kissing_component;
 pub mod kissing_component_bridge;
 pub mod kissing_component_data;
+pub mod kissing_component_data_object;
+pub mod kissing_component_enum;
+pub mod kissing_component_error;
 pub mod kissing_component_field;
+pub mod kissing_component_field_hint;
+pub mod kissing_component_group;
+pub mod kissing_component_persist;
+pub mod kissing_reflect_component;
+pub mod kissing_reflect_component_object;
@@ -1,5 +1,7 @@
 use crate::{kissing_event::kissing_event_data::KissingEventData, kissing_registry::GetData};
 
+use std::sync::OnceLock;
+
 use bevy::{ecs::world::CommandQueue, prelude::*};
 use godot::prelude::*;
 //use std::{collections::HashMap, sync::LazyLock};
@@ -42,6 +44,7 @@ pub(crate) type TriggerCallback =
 /// Used by inventory to store references to static functions for editor events.
 pub struct KissingEventCallbacks {
 	pub(crate) kissing_event_data: fn() -> KissingEventData,
+	cached_data: OnceLock<KissingEventData>,
 	// commands_callback: fn(&mut Commands) -> (),
 	// untyped_slot_callback: UntypedSlotCallback,
 	pub(crate) trigger: TriggerCallback,
@@ -56,6 +59,7 @@ impl KissingEventCallbacks {
 	) -> Self {
 		Self {
 			kissing_event_data,
+			cached_data: OnceLock::new(),
 			// commands_callback,
 			// untyped_slot_callback,
 			trigger,
@@ -69,7 +73,7 @@ impl KissingEventCallbacks {
 
 impl GetData for KissingEventCallbacks {
 	type Data = KissingEventData;
-	fn get_data(&self) -> KissingEventData {
-		(self.kissing_event_data)()
+	fn get_data(&self) -> &KissingEventData {
+		self.cached_data.get_or_init(self.kissing_event_data)
 	}
 }
@@ -0,0 +1,138 @@
+use crate::{
+	kissing_function::{
+		kissing_function_data::KissingFunctionData, kissing_function_error::KissingFunctionError,
+	},
+	kissing_registry::GetData,
+};
+
+use std::{
+	collections::HashMap,
+	sync::{LazyLock, OnceLock},
+};
+
+use bevy::{ecs::system::SystemInput, prelude::*};
+use godot::prelude::*;
+
+// -----------
+// * Exports *
+// -----------
+
+/// The `In<T>` input of a `#[kissing_function]` system.
+/// `pub` since used in "user code" generated via macro code.
+pub use bevy::prelude::In;
+
+// -------------------------
+// * Top-Level Macro Calls *
+// -------------------------
+
+inventory::collect!(KissingFunction);
+
+// -------------------------
+// * Top-Level Static Vars *
+// -------------------------
+
+/// A `HashMap` that, given the name of a `#[kissing_function]` system, returns its `call`.
+pub static FUNCTION_NAME_TO_FUNC: LazyLock<HashMap<StringName, &CallKissingFunctionCallback>> =
+	LazyLock::new(|| {
+		let mut function_name_to_func = HashMap::<StringName, &CallKissingFunctionCallback>::new();
+		for kissing_function in inventory::iter::<KissingFunction>() {
+			let data = kissing_function.get_data();
+			let name = StringName::from(data.name);
+			function_name_to_func.insert(name, kissing_function.get_call());
+		}
+		function_name_to_func
+	});
+
+// ----------------
+// * Type Aliases *
+// ----------------
+
+/// A reference to a kissing function's static function that converts the Godot arguments,
+/// runs the one-shot system, and converts its output back to a `Variant`.
+type CallKissingFunctionCallback =
+	fn(world: &mut World, args: &[&Variant]) -> Result<Variant, KissingFunctionError>;
+
+// -----------
+// * Structs *
+// -----------
+
+/// Used by inventory to store references to static functions for one-shot systems callable from Godot.
+pub struct KissingFunction {
+	kissing_function_data: fn() -> KissingFunctionData,
+	cached_data: OnceLock<KissingFunctionData>,
+	call: CallKissingFunctionCallback,
+}
+
+impl KissingFunction {
+	pub const fn new(
+		kissing_function_data: fn() -> KissingFunctionData,
+		call: CallKissingFunctionCallback,
+	) -> Self {
+		Self {
+			kissing_function_data,
+			cached_data: OnceLock::new(),
+			call,
+		}
+	}
+
+	pub fn get_call(&self) -> &CallKissingFunctionCallback {
+		&self.call
+	}
+}
+
+impl GetData for KissingFunction {
+	type Data = KissingFunctionData;
+	fn get_data(&self) -> &KissingFunctionData {
+		self.cached_data.get_or_init(self.kissing_function_data)
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Returns an error if the number of arguments passed from Godot doesn't match the system's.
+/// `pub` since used in "user code" generated via macro code.
+pub fn expect_argument_count(args: &[&Variant], expected: usize) -> Result<(), KissingFunctionError> {
+	if args.len() != expected {
+		return Err(KissingFunctionError::WrongArgumentCount {
+			expected,
+			got: args.len(),
+		});
+	}
+	Ok(())
+}
+
+/// Converts the argument at `index` to `T`.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_argument<T: FromGodot>(args: &[&Variant], index: usize) -> Result<T, KissingFunctionError> {
+	let Some(arg) = args.get(index) else {
+		return Err(KissingFunctionError::WrongArgumentCount {
+			expected: index + 1,
+			got: args.len(),
+		});
+	};
+	arg.try_to::<T>()
+		.map_err(|e| KissingFunctionError::InvalidArgument {
+			index,
+			reason: e.to_string(),
+		})
+}
+
+/// Runs `system` as a cached one-shot system and converts its output to a `Variant`.
+/// `pub` since used in "user code" generated via macro code.
+pub fn run_one_shot_system<I, O, M, S>(
+	world: &mut World,
+	system: S,
+	input: I::Inner<'_>,
+) -> Result<Variant, KissingFunctionError>
+where
+	I: SystemInput + 'static,
+	O: ToGodot + 'static,
+	S: IntoSystem<I, O, M> + 'static,
+{
+	world
+		.run_system_cached_with(system, input)
+		.map(|output| output.to_variant())
+		.map_err(|e| KissingFunctionError::SystemFailed(e.to_string()))
+}
@@ -0,0 +1,24 @@
+use crate::kissing_registry::ToGodotDictionary;
+
+use godot::prelude::*;
+
+/// A structure containing the data for a "kissing" function.
+pub struct KissingFunctionData {
+	pub name: &'static str,
+	pub argument_names: &'static [&'static str],
+	pub docs: &'static str,
+}
+
+impl ToGodotDictionary for KissingFunctionData {
+	fn to_dictionary(&self) -> VarDictionary {
+		vdict! {
+			"name" => self.name,
+			"argument_count" => self.argument_names.len() as u32,
+			"argument_names" => &self.argument_names
+				.iter()
+				.map(|n| GString::from(*n))
+				.collect::<PackedStringArray>(),
+			"docs" => self.docs,
+		}
+	}
+}
@@ -0,0 +1,21 @@
+/// An error that occurs while calling a "kissing" function from Godot.
+#[derive(Debug, thiserror::Error)]
+pub enum KissingFunctionError {
+	#[error("no Bevy app is running")]
+	NoApp,
+
+	#[error("the Bevy app is already processing")]
+	AppBusy,
+
+	#[error("could not find kissing function of name {0}")]
+	NotFound(String),
+
+	#[error("expected {expected} arguments, got {got}")]
+	WrongArgumentCount { expected: usize, got: usize },
+
+	#[error("argument {index} could not be converted ({reason})")]
+	InvalidArgument { index: usize, reason: String },
+
+	#[error("system failed to run ({0})")]
+	SystemFailed(String),
+}
@@ -0,0 +1,3 @@
+pub mod kissing_function;
+pub mod kissing_function_data;
+pub mod kissing_function_error;
@@ -54,6 +54,11 @@ impl KissingNode {
 		entity: Entity,
 	) -> () {
 		let mut e = world.entity_mut(entity);
+
+		#[cfg(not(feature = "godot_class"))]
 		crate::components::godot_node::GodotNode::<T>::add_components_from_type(&mut e);
+
+		#[cfg(feature = "godot_class")]
+		e.insert(crate::components::godot_class::GodotClass::of::<T>());
 	}
 }
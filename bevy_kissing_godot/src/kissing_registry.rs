@@ -1,10 +1,17 @@
 use crate::{
 	kissing_component::{
-		kissing_component::KissingComponent, kissing_component_data::KissingComponentData,
+		kissing_component::KissingComponent, kissing_component_bridge,
+		kissing_component_data::KissingComponentData,
 	},
 	kissing_event::{
 		kissing_event_callbacks::KissingEventCallbacks, kissing_event_data::KissingEventData,
 	},
+	kissing_function::{
+		kissing_function::KissingFunction, kissing_function_data::KissingFunctionData,
+	},
+	kissing_resource::{
+		kissing_resource::KissingResource, kissing_resource_data::KissingResourceData,
+	},
 };
 use bevy_kissing_godot_macros::get_compilation_timestamp;
 
@@ -14,9 +21,12 @@ use godot::prelude::*;
 // * Traits *
 // ----------
 
+/// Gives access to the data of an inventory entry.
+///
+/// The data is built the first time it's requested and cached, since building it allocates.
 pub(crate) trait GetData {
 	type Data;
-	fn get_data(&self) -> Self::Data;
+	fn get_data(&self) -> &Self::Data;
 }
 
 pub(crate) trait ToGodotDictionary {
@@ -43,6 +53,26 @@ impl KissingRegistry {
 		Self::collect_inventory_as_godot_array::<KissingComponent, KissingComponentData>()
 	}
 
+	/// Upgrades the `data` of a "kissing" component saved with `version` to the component's
+	/// current fields, running its renames and migration.
+	///
+	/// The component editor uses this so edited data can be saved with the current version.
+	/// Returns `data` unchanged and prints an error if there's no component named `name`.
+	#[func]
+	pub fn migrate_kissing_component_data(
+		name: StringName,
+		version: u32,
+		data: VarDictionary,
+	) -> VarDictionary {
+		match kissing_component_bridge::migrate_component_data(&name, version, data.clone()) {
+			Ok(data) => data,
+			Err(e) => {
+				godot_error!("Could not migrate Bevy Component data (reason: {}).", e);
+				data
+			}
+		}
+	}
+
 	/// Provides the "kissing" event data in a Godot-compatible format.
 	///
 	/// The key/value pairs of the [`VarDictionary`] correlate to the fields of [`KissingEventData`].
@@ -51,6 +81,22 @@ impl KissingRegistry {
 		Self::collect_inventory_as_godot_array::<KissingEventCallbacks, KissingEventData>()
 	}
 
+	/// Provides the "kissing" function data in a Godot-compatible format.
+	///
+	/// The key/value pairs of the [`VarDictionary`] correlate to the fields of [`KissingFunctionData`].
+	#[func]
+	pub fn find_all_kissing_functions() -> Array<VarDictionary> {
+		Self::collect_inventory_as_godot_array::<KissingFunction, KissingFunctionData>()
+	}
+
+	/// Provides the "kissing" resource data in a Godot-compatible format.
+	///
+	/// The key/value pairs of the [`VarDictionary`] correlate to the fields of [`KissingResourceData`].
+	#[func]
+	pub fn find_all_kissing_resources() -> Array<VarDictionary> {
+		Self::collect_inventory_as_godot_array::<KissingResource, KissingResourceData>()
+	}
+
 	/// Collects inventory of types that have data and returns them as a Godot array of dictionaries.
 	fn collect_inventory_as_godot_array<
 		T: GetData<Data = D> + inventory::Collect,
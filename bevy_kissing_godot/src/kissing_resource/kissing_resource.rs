@@ -0,0 +1,110 @@
+use crate::{
+	kissing_registry::GetData, kissing_resource::kissing_resource_data::KissingResourceData,
+};
+
+use std::{
+	collections::HashMap,
+	sync::{LazyLock, OnceLock},
+};
+
+use bevy::{ecs::change_detection::Tick, prelude::*};
+use godot::prelude::*;
+
+// -------------------------
+// * Top-Level Macro Calls *
+// -------------------------
+
+inventory::collect!(KissingResource);
+
+// -------------------------
+// * Top-Level Static Vars *
+// -------------------------
+
+/// A `HashMap` that, given a name of a `KissingResource`-derived `Resource`,
+/// returns its inventory entry.
+pub static RESOURCE_NAME_TO_KISSING_RESOURCE: LazyLock<
+	HashMap<StringName, &'static KissingResource>,
+> = LazyLock::new(|| {
+	let mut resource_name_to_kissing_resource =
+		HashMap::<StringName, &'static KissingResource>::new();
+	for kissing_resource in inventory::iter::<KissingResource>() {
+		let data = kissing_resource.get_data();
+		let name = StringName::from(data.name);
+		resource_name_to_kissing_resource.insert(name, kissing_resource);
+	}
+	resource_name_to_kissing_resource
+});
+
+// ----------------
+// * Type Aliases *
+// ----------------
+
+/// A reference to a resource's static function that converts its exported fields to a dictionary.
+/// Returns `None` if the resource doesn't exist in the world.
+type ResourceToDictionaryCallback = fn(world: &World) -> Option<VarDictionary>;
+
+/// A reference to a resource's static function that sets one exported field by name.
+/// Returns `false` if the resource or field doesn't exist, or the value has the wrong type.
+type SetResourceFieldCallback = fn(world: &mut World, field: &str, value: &Variant) -> bool;
+
+/// A reference to a generic function that checks Bevy's change detection for a resource.
+type IsResourceChangedCallback = fn(world: &World, last_run: Tick) -> bool;
+
+// -----------
+// * Structs *
+// -----------
+
+/// Used by inventory to store references to static functions for resources exposed to Godot.
+pub struct KissingResource {
+	kissing_resource_data: fn() -> KissingResourceData,
+	cached_data: OnceLock<KissingResourceData>,
+	resource_to_dictionary: ResourceToDictionaryCallback,
+	set_resource_field: SetResourceFieldCallback,
+	is_resource_changed: IsResourceChangedCallback,
+}
+
+impl KissingResource {
+	pub const fn new<T: Resource>(
+		kissing_resource_data: fn() -> KissingResourceData,
+		resource_to_dictionary: ResourceToDictionaryCallback,
+		set_resource_field: SetResourceFieldCallback,
+	) -> Self {
+		Self {
+			kissing_resource_data,
+			cached_data: OnceLock::new(),
+			resource_to_dictionary,
+			set_resource_field,
+			is_resource_changed: is_resource_changed::<T>,
+		}
+	}
+
+	pub fn get_resource_to_dictionary(&self) -> &ResourceToDictionaryCallback {
+		&self.resource_to_dictionary
+	}
+
+	pub fn get_set_resource_field(&self) -> &SetResourceFieldCallback {
+		&self.set_resource_field
+	}
+
+	pub fn get_is_resource_changed(&self) -> &IsResourceChangedCallback {
+		&self.is_resource_changed
+	}
+}
+
+impl GetData for KissingResource {
+	type Data = KissingResourceData;
+	fn get_data(&self) -> &KissingResourceData {
+		self.cached_data.get_or_init(self.kissing_resource_data)
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Returns `true` if `T` was added or changed after `last_run`.
+fn is_resource_changed<T: Resource>(world: &World, last_run: Tick) -> bool {
+	world
+		.get_resource_change_ticks::<T>()
+		.is_some_and(|ticks| ticks.is_changed(last_run, world.read_change_tick()))
+}
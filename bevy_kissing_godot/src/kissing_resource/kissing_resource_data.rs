@@ -0,0 +1,26 @@
+use crate::{
+	kissing_component::kissing_component_data::KissingComponentFieldData,
+	kissing_registry::ToGodotDictionary,
+};
+
+use godot::prelude::*;
+
+/// A structure containing the data for a "kissing" resource.
+pub struct KissingResourceData {
+	pub name: &'static str,
+	pub docs: &'static str,
+	pub fields: Vec<KissingComponentFieldData>,
+}
+
+impl ToGodotDictionary for KissingResourceData {
+	fn to_dictionary(&self) -> VarDictionary {
+		vdict! {
+			"name" => self.name,
+			"docs" => self.docs,
+			"fields" => &self.fields
+				.iter()
+				.map(|s| s.to_dictionary())
+				.collect::<Array<VarDictionary>>(),
+		}
+	}
+}
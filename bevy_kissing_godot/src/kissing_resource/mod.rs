@@ -0,0 +1,2 @@
+pub mod kissing_resource;
+pub mod kissing_resource_data;
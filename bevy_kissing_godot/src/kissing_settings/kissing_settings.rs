@@ -0,0 +1,116 @@
+use crate::{
+	kissing_component::{
+		kissing_component_field::KissingComponentField,
+		kissing_component_field_hint::KissingComponentFieldHint,
+	},
+	kissing_settings::kissing_settings_data::{KissingSettingsData, KissingSettingsFieldData},
+};
+
+use bevy::prelude::*;
+use godot::{classes::ProjectSettings, prelude::*};
+
+// -------------------------
+// * Top-Level Macro Calls *
+// -------------------------
+
+inventory::collect!(KissingSettings);
+
+// ----------------
+// * Type Aliases *
+// ----------------
+
+/// A reference to a settings resource's static function that reads its fields from
+/// `ProjectSettings` and inserts the resource, or updates its exported fields if it exists.
+type InsertFromProjectSettingsCallback = fn(world: &mut World);
+
+// -----------
+// * Structs *
+// -----------
+
+/// Used by inventory to store references to static functions for resources loaded from `ProjectSettings`.
+pub struct KissingSettings {
+	kissing_settings_data: fn() -> KissingSettingsData,
+	insert_from_project_settings: InsertFromProjectSettingsCallback,
+}
+
+impl KissingSettings {
+	pub const fn new(
+		kissing_settings_data: fn() -> KissingSettingsData,
+		insert_from_project_settings: InsertFromProjectSettingsCallback,
+	) -> Self {
+		Self {
+			kissing_settings_data,
+			insert_from_project_settings,
+		}
+	}
+
+	/// Builds the settings data. It isn't cached like `GetData` since `Variant` isn't `Sync`.
+	pub fn get_data(&self) -> KissingSettingsData {
+		(self.kissing_settings_data)()
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Registers the `ProjectSettings` entries of every settings resource and inserts the resources.
+pub(crate) fn load_kissing_settings(world: &mut World) {
+	for kissing_settings in inventory::iter::<KissingSettings>() {
+		let data = kissing_settings.get_data();
+		for field in &data.fields {
+			register_project_setting(field);
+		}
+		(kissing_settings.insert_from_project_settings)(world);
+	}
+}
+
+/// Re-reads every settings resource with `reload_on_change` enabled.
+pub(crate) fn reload_kissing_settings(world: &mut World) {
+	for kissing_settings in inventory::iter::<KissingSettings>() {
+		if kissing_settings.get_data().reload_on_change {
+			(kissing_settings.insert_from_project_settings)(world);
+		}
+	}
+}
+
+/// Returns `true` if any settings resource wants to be re-read when `ProjectSettings` change.
+pub(crate) fn has_reloadable_kissing_settings() -> bool {
+	inventory::iter::<KissingSettings>().any(|s| s.get_data().reload_on_change)
+}
+
+/// Adds `field` to `ProjectSettings` if it doesn't exist yet, and registers its default and hints
+/// so it shows up properly in the Project Settings dialog.
+fn register_project_setting(field: &KissingSettingsFieldData) {
+	let mut project_settings = ProjectSettings::singleton();
+	if !project_settings.has_setting(field.key) {
+		project_settings.set_setting(field.key, &field.default_value);
+	}
+	project_settings.set_initial_value(field.key, &field.default_value);
+	project_settings.add_property_info(&field.to_property_info());
+}
+
+/// Reads `key` from `ProjectSettings` and constrains it to `hint`, falling back to `default`
+/// (with a warning) if the stored value has the wrong type.
+/// `pub` since used in "user code" generated via macro code.
+pub fn get_project_setting<T: KissingComponentField>(
+	key: &str,
+	default: T,
+	hint: KissingComponentFieldHint,
+) -> T {
+	let value = ProjectSettings::singleton().get_setting(key);
+	if value.is_nil() {
+		return default;
+	}
+	match hint.constrain(value, key).try_to::<T::EditorType>() {
+		Ok(value) => T::from_editor_value(value),
+		Err(e) => {
+			godot_warn!(
+				"Project setting {} could not be converted, using default instead (reason: {}).",
+				key,
+				e
+			);
+			default
+		}
+	}
+}
@@ -0,0 +1,39 @@
+use godot::prelude::*;
+
+use crate::kissing_component::kissing_component_field_hint::KissingComponentFieldHint;
+
+/// A structure containing the data for a Bevy resource loaded from `ProjectSettings`.
+pub struct KissingSettingsData {
+	pub name: &'static str,
+	pub docs: &'static str,
+
+	/// If `true`, the resource is re-read whenever `ProjectSettings` emits `settings_changed`.
+	pub reload_on_change: bool,
+
+	pub fields: Vec<KissingSettingsFieldData>,
+}
+
+/// A structure containing the data for one `ProjectSettings` entry of a settings resource.
+pub struct KissingSettingsFieldData {
+	pub name: &'static str,
+
+	/// The full `ProjectSettings` key, such as `"game/player/speed"`.
+	pub key: &'static str,
+
+	pub default_value: Variant,
+
+	/// The editor hint from `#[export(range = ...)]`, `#[export(file = ...)]`, etc.
+	pub hint: KissingComponentFieldHint,
+}
+
+impl KissingSettingsFieldData {
+	/// The property info passed to `ProjectSettings::add_property_info`.
+	pub fn to_property_info(&self) -> VarDictionary {
+		vdict! {
+			"name" => self.key,
+			"type" => self.default_value.get_type().ord(),
+			"hint" => self.hint.property_hint().ord(),
+			"hint_string" => self.hint.hint_string(),
+		}
+	}
+}
@@ -0,0 +1,2 @@
+pub mod kissing_settings;
+pub mod kissing_settings_data;
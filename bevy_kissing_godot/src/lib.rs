@@ -6,8 +6,11 @@ pub mod extensions;
 pub mod kissing_app;
 pub mod kissing_component;
 pub mod kissing_event;
+pub mod kissing_function;
 pub mod kissing_node;
 pub mod kissing_registry;
+pub mod kissing_resource;
+pub mod kissing_settings;
 pub mod nodes;
 pub mod plugins;
 pub mod prelude;
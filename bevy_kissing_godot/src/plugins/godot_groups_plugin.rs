@@ -0,0 +1,57 @@
+use crate::{
+	components::godot_groups::set_godot_group,
+	events::{add_to_group::on_add_to_group, remove_from_group::on_remove_from_group},
+	prelude::{AllNodes, GodotNodeId},
+};
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+/// Inserts `GodotGroups` and the group markers registered with
+/// [`crate::godot_group_marker!`] on entities when their node is set up, and enables the
+/// `AddToGroup` and `RemoveFromGroup` events.
+///
+/// ```
+/// #[kiss_bevy(node_name = MyApp)]
+/// fn main(app: &mut App) {
+///     app.add_plugins(GodotGroupsPlugin);
+/// }
+/// ```
+pub struct GodotGroupsPlugin;
+
+impl Plugin for GodotGroupsPlugin {
+	fn build(&self, app: &mut App) {
+		app.add_observer(on_godot_node_id_added)
+			.add_observer(on_add_to_group)
+			.add_observer(on_remove_from_group);
+	}
+}
+
+fn on_godot_node_id_added(
+	add: On<Add, GodotNodeId>,
+	ids: Query<&GodotNodeId>,
+	all_nodes: NonSend<AllNodes>,
+	mut commands: Commands,
+) {
+	let Some(node) = ids
+		.get(add.entity)
+		.ok()
+		.and_then(|id| id.try_get_as::<Node>(&all_nodes))
+	else {
+		return;
+	};
+
+	// Groups starting with an underscore are used internally by Godot.
+	let groups: Vec<String> = node
+		.get_groups()
+		.iter_shared()
+		.map(|group| group.to_string())
+		.filter(|group| !group.starts_with('_'))
+		.collect();
+
+	commands.entity(add.entity).queue(move |mut entity: EntityWorldMut| {
+		for group in &groups {
+			set_godot_group(&mut entity, group, true);
+		}
+	});
+}
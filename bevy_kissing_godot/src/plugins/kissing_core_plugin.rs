@@ -1,4 +1,13 @@
-use crate::{prelude::*, resources::entity_preregister::EntityPreregister};
+use crate::{
+	kissing_component::kissing_component::{COMPONENT_NAME_TO_KISSING_COMPONENT, KissingComponent},
+	prelude::*,
+	resources::{
+		entity_preregister::EntityPreregister, node_entity_map::NodeEntityMap,
+		node_path_cache::NodePathCache, pending_entity_references::PendingEntityReferences,
+	},
+};
+
+use std::sync::LazyLock;
 
 use bevy::prelude::*;
 
@@ -16,6 +25,17 @@ impl Plugin for KissingCorePlugin {
 		app.insert_non_send_resource(AllNodes::default())
 			.insert_non_send_resource(AllResources::default())
 			.insert_non_send_resource(GodotThreadEnsurer::new())
-			.insert_non_send_resource(EntityPreregister::default());
+			.insert_non_send_resource(EntityPreregister::default())
+			.insert_non_send_resource(NodeEntityMap::default())
+			.insert_non_send_resource(NodePathCache::default())
+			.insert_non_send_resource(PendingEntityReferences::default());
+
+		// Hooks can only be registered before the components are added to any entity.
+		for kissing_component in inventory::iter::<KissingComponent>() {
+			kissing_component.register_hooks(app.world_mut());
+		}
+
+		// Build the component names now, so duplicate names are reported when the app starts.
+		LazyLock::force(&COMPONENT_NAME_TO_KISSING_COMPONENT);
 	}
 }
@@ -1,3 +1,4 @@
+pub mod godot_groups_plugin;
 pub mod kissing_core_plugin;
 
 #[cfg(feature = "input")]
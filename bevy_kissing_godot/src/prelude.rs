@@ -10,19 +10,32 @@
 
 pub use crate::bevy_entity_ready::BevyEntityReady;
 pub use crate::components::{
-	gd_tracker_id::GodotNodeId, gd_tracker_id::GodotResourceId, godot_node::GodotNode,
+	gd_tracker_id::GodotNodeId, gd_tracker_id::GodotResourceId, gd_tracker_ref::GodotNodeRef,
+	gd_tracker_ref::GodotResourceRef, godot_groups::GodotGroupMarker, godot_groups::GodotGroups,
+	godot_node::GodotNode,
+	godot_resource_holder::{GodotResourceHolder, release_godot_resources, retain_godot_resources},
 };
+pub use crate::events::{add_to_group::AddToGroup, remove_from_group::RemoveFromGroup};
 pub use crate::extensions::{entity::EntityExt, variant::VariantExt};
-pub use crate::kissing_component::kissing_component_field::KissingComponentField;
-pub use crate::plugins::kissing_core_plugin::KissingCorePlugin;
+pub use crate::kissing_component::{
+	kissing_component_enum::KissingComponentEnum, kissing_component_field::KissingComponentField,
+	kissing_component_group::KissingComponentGroup, kissing_component_persist::PersistComponents,
+};
+pub use crate::godot_group_marker;
+pub use crate::kissing_reflect_component;
+pub use crate::plugins::{
+	godot_groups_plugin::GodotGroupsPlugin, kissing_core_plugin::KissingCorePlugin,
+};
 pub use crate::resources::{
 	gd_handle::GdHandle, gd_tracker::AllNodes, gd_tracker::AllResources,
 	godot_thread_ensurer::GodotThreadEnsurer, input_event_argument::InputEventArgument,
-	physics_process_delta::PhysicsProcessDelta, process_delta::ProcessDelta,
+	node_entity_map::NodeEntityMap, physics_process_delta::PhysicsProcessDelta,
+	process_delta::ProcessDelta,
 };
 pub use crate::scedules::{PhysicsProcess, Process};
 pub use crate::types::{
-	GodotNodeQueryUtils, QueryGodotNode, QueryGodotNodeWith, SingleGodotNode, SingleGodotNodeWith,
+	GodotNodeClass, GodotNodeLookup, GodotNodeQueryUtils, GodotNodes, GodotNodesMut,
+	QueryGodotNode, QueryGodotNodeWith, SingleGodotNode, SingleGodotNodeWith,
 };
 
 // -----------------------
@@ -32,6 +45,10 @@ pub use crate::types::{
 #[cfg(feature = "input")]
 pub use crate::scedules::GodotInput;
 
+// `GodotClass` isn't exported since it would conflict with `godot::prelude::GodotClass`.
+#[cfg(feature = "godot_class")]
+pub use crate::components::godot_class::IsA;
+
 #[cfg(feature = "node_triggerables")]
 pub use crate::{
 	events::{
@@ -48,7 +65,9 @@ pub use crate::{
 // -----------------
 
 pub use bevy_kissing_godot_macros::{
-	KissingComponent, KissingEvent, KissingNode, kiss_bevy, plugin_and_kissing_component,
+	GodotGroupMarker, KissingComponent, KissingComponentEnum, KissingComponentGroup, KissingEvent,
+	KissingNode, KissingResource, KissingSettings, kiss_bevy, kissing_function,
+	plugin_and_kissing_component,
 };
 
 // -----------------------------
@@ -14,6 +14,7 @@ use godot::prelude::*;
 
 pub trait GdTrackerErrorStrings {
 	const COULD_NOT_GET_OBJECT: &'static str;
+	const STALE_ID: &'static str;
 }
 
 // ------------
@@ -30,6 +31,7 @@ pub type AllNodes = GdTracker<Node, GodotNodeId, AllNodesErrorStrings>;
 pub struct AllNodesErrorStrings;
 impl GdTrackerErrorStrings for AllNodesErrorStrings {
 	const COULD_NOT_GET_OBJECT: &'static str = "could not get node from AllNodes";
+	const STALE_ID: &'static str = "GodotNodeId refers to a node that was removed from AllNodes";
 }
 
 // ----------------
@@ -47,6 +49,8 @@ pub type AllResources =
 pub struct AllResourcesErrorStrings;
 impl GdTrackerErrorStrings for AllResourcesErrorStrings {
 	const COULD_NOT_GET_OBJECT: &'static str = "could not get resource from AllResources";
+	const STALE_ID: &'static str =
+		"GodotResourceId refers to a resource that was removed from AllResources";
 }
 
 // -------------
@@ -56,6 +60,16 @@ impl GdTrackerErrorStrings for AllResourcesErrorStrings {
 /// The underlying implementation for [`AllNodes`] and [`AllResources`].
 pub struct GdTracker<T: GodotClass, TrackerType: GdTrackerId, ErrorStrings: GdTrackerErrorStrings> {
 	gd_objects: Vec<Option<Gd<T>>>,
+
+	/// The generation of each slot in `gd_objects`, increased whenever its object is removed
+	/// so ids created before then no longer resolve to the slot's next object.
+	generations: Vec<u32>,
+
+	/// How many components reference each slot in `gd_objects`. Slots that were never retained
+	/// are `None` and kept until removed explicitly, while retained slots are removed by
+	/// [`GdTracker::sweep`] once their count drops to zero.
+	ref_counts: Vec<Option<usize>>,
+
 	empty_indexes: Vec<usize>,
 	instance_id_to_tracker_id: BTreeMap<InstanceId, usize>,
 	_spooky: (
@@ -70,6 +84,8 @@ impl<T: GodotClass, TrackerType: GdTrackerId, ErrorStrings: GdTrackerErrorString
 	fn default() -> Self {
 		Self {
 			gd_objects: vec![],
+			generations: vec![],
+			ref_counts: vec![],
 			empty_indexes: vec![],
 			instance_id_to_tracker_id: BTreeMap::default(),
 			_spooky: Default::default(),
@@ -94,14 +110,14 @@ impl<T: GodotClass, TrackerType: GdTrackerId, ErrorStrings: GdTrackerErrorString
 	pub fn get_id_from_instance_id(&self, instance_id: &InstanceId) -> Option<TrackerType> {
 		self.instance_id_to_tracker_id
 			.get(&instance_id)
-			.map(|id| TrackerType::new(*id))
+			.map(|id| TrackerType::new(*id, self.generations[*id]))
 	}
 
 	pub(crate) fn register(&mut self, gd_object: Gd<T>) -> TrackerType {
 		let instance_id = gd_object.instance_id();
 		let id = self.register_impl(gd_object);
 		self.instance_id_to_tracker_id.insert(instance_id, id);
-		TrackerType::new(id)
+		TrackerType::new(id, self.generations[id])
 	}
 
 	fn register_impl(&mut self, gd_object: Gd<T>) -> usize {
@@ -110,26 +126,74 @@ impl<T: GodotClass, TrackerType: GdTrackerId, ErrorStrings: GdTrackerErrorString
 			// Checks if there is a valid element of value `None` at `new_index`.
 			if let Some(None) = self.gd_objects.get(new_index) {
 				self.gd_objects[new_index] = gd_object.into();
+				self.ref_counts[new_index] = None;
 				return new_index;
 			}
 		}
 
 		// If no free indexes, just add to the end.
 		self.gd_objects.push(gd_object.into());
+		self.generations.push(0);
+		self.ref_counts.push(None);
 		self.gd_objects.len() - 1
 	}
 
-	pub(crate) fn get(&self, index: usize) -> Gd<T> {
+	pub(crate) fn get(&self, index: usize, generation: u32) -> Gd<T> {
+		if self.generations.get(index).is_some_and(|g| *g != generation) {
+			panic!("{}", ErrorStrings::STALE_ID);
+		}
 		match self.gd_objects.get(index) {
 			Some(n) => n.clone().unwrap(),
 			None => panic!("{}", ErrorStrings::COULD_NOT_GET_OBJECT),
 		}
 	}
 
-	pub(crate) fn try_get(&self, index: usize) -> Option<Gd<T>> {
+	/// Returns `None` if the object was removed, even if its slot was reused since.
+	pub(crate) fn try_get(&self, index: usize, generation: u32) -> Option<Gd<T>> {
+		if self.generations.get(index) != Some(&generation) {
+			return None;
+		}
 		self.gd_objects.get(index).and_then(|a| a.clone())
 	}
 
+	/// Adds a reference from a component to the object of `id`.
+	/// `pub` since used in "user code" generated via macro code.
+	pub fn retain(&mut self, id: &TrackerType) {
+		if let Some(ref_count) = self.ref_count_mut(id) {
+			*ref_count = Some(ref_count.unwrap_or_default() + 1);
+		}
+	}
+
+	/// Removes a reference added with [`GdTracker::retain`].
+	/// `pub` since used in "user code" generated via macro code.
+	pub fn release(&mut self, id: &TrackerType) {
+		if let Some(Some(ref_count)) = self.ref_count_mut(id) {
+			*ref_count = ref_count.saturating_sub(1);
+		}
+	}
+
+	/// Removes every object whose references were all released, returning how many were removed.
+	pub(crate) fn sweep(&mut self) -> usize {
+		let unreferenced = self
+			.ref_counts
+			.iter()
+			.enumerate()
+			.filter(|(_, ref_count)| **ref_count == Some(0))
+			.filter_map(|(index, _)| self.gd_objects[index].as_ref().map(|g| g.instance_id()))
+			.collect::<Vec<_>>();
+		for instance_id in &unreferenced {
+			self.remove(instance_id);
+		}
+		unreferenced.len()
+	}
+
+	fn ref_count_mut(&mut self, id: &TrackerType) -> Option<&mut Option<usize>> {
+		if self.generations.get(id.index()) != Some(&id.generation()) {
+			return None;
+		}
+		self.ref_counts.get_mut(id.index())
+	}
+
 	pub(crate) fn remove(&mut self, instance_id: &InstanceId) {
 		let Some(id) = self.instance_id_to_tracker_id.remove(instance_id) else {
 			return;
@@ -138,6 +202,8 @@ impl<T: GodotClass, TrackerType: GdTrackerId, ErrorStrings: GdTrackerErrorString
 			return;
 		};
 		let _ = gd_object.take();
+		self.generations[id] = self.generations[id].wrapping_add(1);
+		self.ref_counts[id] = None;
 		self.empty_indexes.push(id);
 	}
 }
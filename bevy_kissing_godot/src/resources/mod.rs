@@ -3,5 +3,8 @@ pub mod gd_handle;
 pub mod gd_tracker;
 pub mod godot_thread_ensurer;
 pub mod input_event_argument;
+pub mod node_entity_map;
+pub mod node_path_cache;
+pub mod pending_entity_references;
 pub mod physics_process_delta;
 pub mod process_delta;
@@ -0,0 +1,45 @@
+use crate::extensions::entity::EntityExt;
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+/// The metadata that stores a node's entity, so GDScript can get it with
+/// `node.get_meta("bevy_entity")`. It's stored in the same format as `EntityExt::to_godot_variant`.
+pub const NODE_ENTITY_META_NAME: &str = "bevy_entity";
+
+/// Maps Godot nodes to the Bevy entities created for them, so code holding a `Gd<Node>`
+/// (such as from a raycast or a signal) can find its entity.
+///
+/// It must be passed to a Bevy function with `bevy::prelude::NonSend` as nodes are
+/// added and removed from the Godot thread.
+#[derive(Default)]
+pub struct NodeEntityMap {
+	instance_id_to_entity: BTreeMap<InstanceId, Entity>,
+}
+
+impl NodeEntityMap {
+	/// Returns the entity of `node`, or `None` if it doesn't have one.
+	pub fn entity_for<T: Inherits<Node>>(&self, node: &Gd<T>) -> Option<Entity> {
+		self.entity_for_instance_id(node.instance_id())
+	}
+
+	/// Returns the entity of the node with `instance_id`, or `None` if it doesn't have one.
+	pub fn entity_for_instance_id(&self, instance_id: InstanceId) -> Option<Entity> {
+		self.instance_id_to_entity.get(&instance_id).copied()
+	}
+
+	pub(crate) fn insert(&mut self, node: &mut Gd<Node>, entity: Entity) {
+		node.set_meta(NODE_ENTITY_META_NAME, &entity.to_godot_variant());
+		self.instance_id_to_entity.insert(node.instance_id(), entity);
+	}
+
+	pub(crate) fn remove(&mut self, instance_id: &InstanceId) -> Option<Entity> {
+		let entity = self.instance_id_to_entity.remove(instance_id)?;
+		if let Ok(mut node) = Gd::<Node>::try_from_instance_id(*instance_id) {
+			node.remove_meta(NODE_ENTITY_META_NAME);
+		}
+		Some(entity)
+	}
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use godot::prelude::*;
+
+/// Caches the nodes found by [`crate::types::GodotNodeLookup`].
+///
+/// The cache is cleared whenever nodes are added to or removed from the `SceneTree`, so moved
+/// nodes are found again. Renaming a node doesn't clear it.
+///
+/// It must be passed to a Bevy function with `bevy::prelude::NonSend` as lookups use
+/// the Godot API.
+#[derive(Default)]
+pub struct NodePathCache {
+	/// Maps a base node and a path relative to it to the node found, or `None` if there was none.
+	lookups: HashMap<(InstanceId, String), Option<InstanceId>>,
+}
+
+impl NodePathCache {
+	/// Returns the node at `path` relative to `base`, using the cached result if there is one.
+	pub(crate) fn find(&mut self, base: &Gd<Node>, path: &str) -> Option<Gd<Node>> {
+		let key = (base.instance_id(), path.to_string());
+		let target = *self.lookups.entry(key).or_insert_with(|| {
+			base.get_node_or_null(path)
+				.map(|target| target.instance_id())
+		});
+		target.and_then(|target| Gd::try_from_instance_id(target).ok())
+	}
+
+	pub(crate) fn clear(&mut self) {
+		self.lookups.clear();
+	}
+}
@@ -0,0 +1,90 @@
+use crate::kissing_component::kissing_component_field::node_path_for_warning;
+
+use bevy::prelude::*;
+use godot::prelude::*;
+
+// ----------------
+// * Type Aliases *
+// ----------------
+
+/// A reference to a component's static function that writes the resolved target entities
+/// into its `#[export_entity]` field. `targets` has one entry per `NodePath` that was deferred.
+pub type ApplyEntityReferencesCallback =
+	for<'a> fn(entity: EntityWorldMut<'a>, targets: &[Option<Entity>]);
+
+// -----------
+// * Structs *
+// -----------
+
+/// An `#[export_entity]` field waiting for its target nodes to get entities.
+pub(crate) struct PendingEntityReference {
+	pub(crate) source: Entity,
+
+	/// The node, component and field names used in warnings about unresolved targets.
+	pub(crate) node_path: String,
+	pub(crate) component_name: &'static str,
+	pub(crate) field_name: &'static str,
+
+	pub(crate) targets: Vec<Option<InstanceId>>,
+	pub(crate) apply: ApplyEntityReferencesCallback,
+}
+
+/// Stores `#[export_entity]` fields until every node in the batch being set up has an entity,
+/// so references to nodes registered later (like later siblings) can still be resolved.
+///
+/// It must be passed to a Bevy function with `bevy::prelude::NonSend` as node lookups
+/// use the Godot API.
+#[derive(Default)]
+pub struct PendingEntityReferences {
+	pending: Vec<PendingEntityReference>,
+}
+
+impl PendingEntityReferences {
+	/// Defers resolving `paths` (relative to `node`) to entities until the current batch of
+	/// nodes is set up, then calls `apply` on `source`.
+	///
+	/// Warnings about paths without a node name `field_name` of `component_name`.
+	/// `pub` since used in "user code" generated via macro code.
+	pub fn defer(
+		&mut self,
+		node: &Gd<Node>,
+		source: Entity,
+		component_name: &'static str,
+		field_name: &'static str,
+		paths: &[NodePath],
+		apply: ApplyEntityReferencesCallback,
+	) {
+		let targets = paths
+			.iter()
+			.map(|path| {
+				if path.is_empty() {
+					return None;
+				}
+				let target = node.get_node_or_null(path);
+				if target.is_none() {
+					godot_warn!(
+						"Field {} of Bevy Component {} on {} points to {}, which doesn't exist, leaving it unset.",
+						field_name,
+						component_name,
+						node_path_for_warning(node),
+						path
+					);
+				}
+				target.map(|t| t.instance_id())
+			})
+			.collect();
+
+		self.pending.push(PendingEntityReference {
+			source,
+			node_path: node_path_for_warning(node),
+			component_name,
+			field_name,
+			targets,
+			apply,
+		});
+	}
+
+	pub(crate) fn take(&mut self) -> Vec<PendingEntityReference> {
+		std::mem::take(&mut self.pending)
+	}
+}
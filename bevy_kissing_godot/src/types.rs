@@ -1,8 +1,16 @@
 use crate::components::gd_tracker_id::GodotNodeId;
-use crate::components::godot_node::GodotNode;
 use crate::resources::gd_tracker::AllNodes;
+use crate::resources::node_entity_map::NodeEntityMap;
+use crate::resources::node_path_cache::NodePathCache;
 
-use bevy::prelude::*;
+use bevy::{
+	ecs::{
+		query::{QueryData, QueryFilter, QueryItem},
+		system::SystemParam,
+	},
+	prelude::*,
+};
+use godot::classes::Engine;
 use godot::prelude::*;
 
 // ---------
@@ -15,7 +23,7 @@ pub type QueryGodotNodeWith<'world, 'state, 'id, T> =
 
 /// An alias for a `Query` for a Godot Node type.
 pub type QueryGodotNode<'world, 'state, 'id, T> =
-	QueryGodotNodeWith<'world, 'state, 'id, GodotNode<T>>;
+	Query<'world, 'state, &'id GodotNodeId, GodotNodeClass<T>>;
 
 /// An alias for a `Single` query for a Godot Node with a component.
 pub type SingleGodotNodeWith<'world, 'state, 'id, T> =
@@ -23,7 +31,15 @@ pub type SingleGodotNodeWith<'world, 'state, 'id, T> =
 
 /// An alias for a `Single` query for a Godot Node type.
 pub type SingleGodotNode<'world, 'state, 'id, T> =
-	SingleGodotNodeWith<'world, 'state, 'id, GodotNode<T>>;
+	Single<'world, 'state, &'id GodotNodeId, GodotNodeClass<T>>;
+
+/// The query filter for entities whose node is of Godot class `T` or inherits from it.
+#[cfg(not(feature = "godot_class"))]
+pub type GodotNodeClass<T> = With<crate::components::godot_node::GodotNode<T>>;
+
+/// The query filter for entities whose node is of Godot class `T` or inherits from it.
+#[cfg(feature = "godot_class")]
+pub type GodotNodeClass<T> = crate::components::godot_class::IsA<T>;
 
 // ----------
 // * Traits *
@@ -40,3 +56,141 @@ impl<'world, 'state, 'id, T: GodotClass + Inherits<Node>> GodotNodeQueryUtils<T>
 		self.get_as::<T>(all_nodes)
 	}
 }
+
+// -----------------
+// * System Params *
+// -----------------
+
+/// A `SystemParam` for the Godot nodes of type `T` that match the filter `F`.
+///
+/// Combines the `Query` and `NonSend<AllNodes>` that are otherwise needed to get a `Gd<T>`:
+/// ```rust
+/// fn hide_sprites(sprites: GodotNodes<Sprite2D, With<Hidden>>) {
+///     for (_, mut sprite) in sprites.iter() {
+///         sprite.set_visible(false);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotNodes<'w, 's, T: GodotClass + Inherits<Node>, F: QueryFilter + 'static = ()> {
+	query: Query<'w, 's, (Entity, &'static GodotNodeId), (GodotNodeClass<T>, F)>,
+	all_nodes: NonSend<'w, AllNodes>,
+}
+
+impl<'w, 's, T: GodotClass + Inherits<Node>, F: QueryFilter + 'static> GodotNodes<'w, 's, T, F> {
+	/// Returns the node of `entity`, or `None` if it doesn't match or its node is gone.
+	pub fn get(&self, entity: Entity) -> Option<Gd<T>> {
+		let (_, id) = self.query.get(entity).ok()?;
+		id.try_get_as::<T>(&self.all_nodes)
+	}
+
+	/// Iterates over the matching entities and their nodes, skipping nodes that are gone.
+	pub fn iter(&self) -> impl Iterator<Item = (Entity, Gd<T>)> + '_ {
+		self.query
+			.iter()
+			.filter_map(|(entity, id)| Some((entity, id.try_get_as::<T>(&self.all_nodes)?)))
+	}
+
+	/// Returns the node if exactly one entity matches.
+	pub fn single(&self) -> Option<Gd<T>> {
+		let (_, id) = self.query.single().ok()?;
+		id.try_get_as::<T>(&self.all_nodes)
+	}
+}
+
+/// A `SystemParam` like [`GodotNodes`] that also gives mutable access to the query data `D`.
+///
+/// ```rust
+/// fn follow_targets(mut followers: GodotNodesMut<Node2D, &mut Follower>) {
+///     for (_, node, mut follower) in followers.iter_mut() {
+///         follower.last_position = node.get_position();
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotNodesMut<
+	'w,
+	's,
+	T: GodotClass + Inherits<Node>,
+	D: QueryData + 'static,
+	F: QueryFilter + 'static = (),
+> {
+	query: Query<'w, 's, (Entity, &'static GodotNodeId, D), (GodotNodeClass<T>, F)>,
+	all_nodes: NonSend<'w, AllNodes>,
+}
+
+impl<'w, 's, T: GodotClass + Inherits<Node>, D: QueryData + 'static, F: QueryFilter + 'static>
+	GodotNodesMut<'w, 's, T, D, F>
+{
+	/// Returns the node and query data of `entity`, or `None` if it doesn't match or its
+	/// node is gone.
+	pub fn get_mut(&mut self, entity: Entity) -> Option<(Gd<T>, QueryItem<'_, 's, D>)> {
+		let (_, id, data) = self.query.get_mut(entity).ok()?;
+		Some((id.try_get_as::<T>(&self.all_nodes)?, data))
+	}
+
+	/// Iterates over the matching entities, their nodes and their query data, skipping nodes
+	/// that are gone.
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, Gd<T>, QueryItem<'_, 's, D>)> + '_ {
+		let all_nodes = &self.all_nodes;
+		self.query
+			.iter_mut()
+			.filter_map(|(entity, id, data)| Some((entity, id.try_get_as::<T>(all_nodes)?, data)))
+	}
+
+	/// Returns the node and query data if exactly one entity matches.
+	pub fn single_mut(&mut self) -> Option<(Gd<T>, QueryItem<'_, 's, D>)> {
+		let (_, id, data) = self.query.single_mut().ok()?;
+		Some((id.try_get_as::<T>(&self.all_nodes)?, data))
+	}
+}
+
+/// A `SystemParam` that finds nodes by `NodePath`, relative to an entity's node or to the
+/// current scene, and returns their `Gd<T>` or entity.
+///
+/// Paths can use scene-unique names like `"%HealthBar"`. Results are cached until nodes are
+/// added to or removed from the `SceneTree`.
+/// ```rust
+/// fn update_health_bar(player: Single<Entity, With<Player>>, mut lookup: GodotNodeLookup) {
+///     if let Some(mut bar) = lookup.node::<ProgressBar>(*player, "%HealthBar") {
+///         bar.set_value(50.0);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotNodeLookup<'w, 's> {
+	ids: Query<'w, 's, &'static GodotNodeId>,
+	all_nodes: NonSend<'w, AllNodes>,
+	node_entity_map: NonSend<'w, NodeEntityMap>,
+	cache: NonSendMut<'w, NodePathCache>,
+}
+
+impl<'w, 's> GodotNodeLookup<'w, 's> {
+	/// Returns the node at `path` relative to the node of `from`.
+	pub fn node<T: Inherits<Node>>(&mut self, from: Entity, path: &str) -> Option<Gd<T>> {
+		let base = self.ids.get(from).ok()?.try_get_as::<Node>(&self.all_nodes)?;
+		self.cache.find(&base, path)?.try_cast::<T>().ok()
+	}
+
+	/// Returns the entity of the node at `path` relative to the node of `from`.
+	pub fn entity(&mut self, from: Entity, path: &str) -> Option<Entity> {
+		let target = self.node::<Node>(from, path)?;
+		self.node_entity_map.entity_for(&target)
+	}
+
+	/// Returns the node at `path` relative to the current scene.
+	pub fn scene_node<T: Inherits<Node>>(&mut self, path: &str) -> Option<Gd<T>> {
+		let scene = Engine::singleton()
+			.get_main_loop()?
+			.try_cast::<SceneTree>()
+			.ok()?
+			.get_current_scene()?;
+		self.cache.find(&scene, path)?.try_cast::<T>().ok()
+	}
+
+	/// Returns the entity of the node at `path` relative to the current scene.
+	pub fn scene_entity(&mut self, path: &str) -> Option<Entity> {
+		let target = self.scene_node::<Node>(path)?;
+		self.node_entity_map.entity_for(&target)
+	}
+}
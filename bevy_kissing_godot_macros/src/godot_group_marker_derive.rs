@@ -0,0 +1,53 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, LitStr, parse_macro_input};
+
+// -------------
+// * Functions *
+// -------------
+
+/// The implementation for `#[derive(GodotGroupMarker)]`.
+pub(crate) fn godot_group_marker_derive_impl(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let ident = &input.ident;
+
+	// Markers are registered through inventory, which can't be generic.
+	if !input.generics.params.is_empty() {
+		return Error::new_spanned(
+			&input.generics,
+			"GodotGroupMarker can't be derived for generic types",
+		)
+		.into_compile_error()
+		.into();
+	}
+
+	// Find the group name from `#[godot_group("name")]`
+	let Some(attr) = input
+		.attrs
+		.iter()
+		.find(|a| a.path().is_ident("godot_group"))
+	else {
+		return Error::new_spanned(
+			ident,
+			"GodotGroupMarker requires the group's name, such as #[godot_group(\"enemies\")]",
+		)
+		.into_compile_error()
+		.into();
+	};
+	let group = match attr.parse_args::<LitStr>() {
+		Ok(group) => group,
+		Err(err) => return err.into_compile_error().into(),
+	};
+
+	let result = quote! {
+		impl bevy_kissing_godot::prelude::GodotGroupMarker for #ident {
+			const GROUP: &'static str = #group;
+		}
+
+		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
+			bevy_kissing_godot::components::godot_groups::GodotGroupMarkerEntry::new::<#ident>()
+		}
+	};
+
+	result.into()
+}
@@ -43,6 +43,145 @@ pub(crate) fn kiss_bevy_impl(attr: TokenStream, item: TokenStream) -> TokenStrea
 
 		#[godot::prelude::godot_api]
 		impl #node_identifier {
+			/// Emitted with the name and exported fields of a `KissingResource` when Bevy changes it.
+			#[signal]
+			fn bevy_resource_changed(
+				resource_name: godot::prelude::StringName,
+				data: godot::prelude::VarDictionary,
+			);
+
+			/// Runs the `#[kissing_function]` system named `name` with `args` and returns its output.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn call_kissing_function(
+				mut this: godot::obj::Gd<Self>,
+				name: godot::prelude::StringName,
+				args: godot::prelude::VarArray,
+			) -> godot::prelude::Variant {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					return bevy_kissing_godot::kissing_app::KissingApp::kissing_function_failed(
+						&name,
+						bevy_kissing_godot::kissing_function::kissing_function_error::KissingFunctionError::AppBusy,
+					);
+				}
+				let args = args.iter_shared().collect::<Vec<godot::prelude::Variant>>();
+				let args = args.iter().collect::<Vec<&godot::prelude::Variant>>();
+				this.bind_mut().app.call_kissing_function(&name, &args)
+			}
+
+			/// Returns the fields of the `KissingComponent` named `name` on `node`'s entity.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn get_component(
+				mut this: godot::obj::Gd<Self>,
+				node: godot::obj::Gd<godot::prelude::Node>,
+				name: godot::prelude::StringName,
+			) -> godot::prelude::VarDictionary {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("get_component");
+					return godot::prelude::VarDictionary::new();
+				}
+				this.bind_mut().app.get_component(node, &name)
+			}
+
+			/// Inserts or replaces the `KissingComponent` named `name` on `node`'s entity.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn set_component(
+				mut this: godot::obj::Gd<Self>,
+				node: godot::obj::Gd<godot::prelude::Node>,
+				name: godot::prelude::StringName,
+				data: godot::prelude::VarDictionary,
+			) -> bool {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("set_component");
+					return false;
+				}
+				this.bind_mut().app.set_component(node, &name, data)
+			}
+
+			/// Returns `true` if `node`'s entity has the `KissingComponent` named `name`.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn has_component(
+				mut this: godot::obj::Gd<Self>,
+				node: godot::obj::Gd<godot::prelude::Node>,
+				name: godot::prelude::StringName,
+			) -> bool {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("has_component");
+					return false;
+				}
+				this.bind_mut().app.has_component(node, &name)
+			}
+
+			/// Removes the `KissingComponent` named `name` from `node`'s entity.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn remove_component(
+				mut this: godot::obj::Gd<Self>,
+				node: godot::obj::Gd<godot::prelude::Node>,
+				name: godot::prelude::StringName,
+			) -> bool {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("remove_component");
+					return false;
+				}
+				this.bind_mut().app.remove_component(node, &name)
+			}
+
+			/// Returns the exported fields of the `KissingResource` named `name`.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn get_resource(
+				mut this: godot::obj::Gd<Self>,
+				name: godot::prelude::StringName,
+			) -> godot::prelude::VarDictionary {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("get_resource");
+					return godot::prelude::VarDictionary::new();
+				}
+				this.bind_mut().app.get_resource(&name)
+			}
+
+			/// Returns the exported field `field` of the `KissingResource` named `name`.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn get_resource_field(
+				mut this: godot::obj::Gd<Self>,
+				name: godot::prelude::StringName,
+				field: godot::prelude::StringName,
+			) -> godot::prelude::Variant {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("get_resource_field");
+					return godot::prelude::Variant::nil();
+				}
+				this.bind_mut().app.get_resource_field(&name, &field)
+			}
+
+			/// Sets the exported field `field` of the `KissingResource` named `name`.
+			///
+			/// Fails with an error instead of panicking if called while Bevy is processing.
+			#[func(gd_self)]
+			fn set_resource_field(
+				mut this: godot::obj::Gd<Self>,
+				name: godot::prelude::StringName,
+				field: godot::prelude::StringName,
+				value: godot::prelude::Variant,
+			) -> bool {
+				if bevy_kissing_godot::kissing_app::KissingApp::is_processing() {
+					bevy_kissing_godot::kissing_app::KissingApp::print_app_busy_error("set_resource_field");
+					return false;
+				}
+				this.bind_mut().app.set_resource_field(&name, &field, &value)
+			}
 		}
 
 		#[godot::prelude::godot_api]
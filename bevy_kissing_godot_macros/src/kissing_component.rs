@@ -1,12 +1,15 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{Attribute, ItemStruct, TypePath, parse_macro_input};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, Expr, Item, LitInt, LitStr, Path, Token, TypePath, parse_macro_input};
 
 // -----------
 // * Modules *
 // -----------
 
 mod generate_component_impl;
+mod generate_enum_component;
 mod generate_godot_object_struct;
 
 // -----------
@@ -18,28 +21,136 @@ mod generate_godot_object_struct;
 struct KissingComponentArguments {
 	on_construct: Option<TypePath>,
 	on_added_to_node: Option<TypePath>,
+	aliases: Vec<LitStr>,
+	version: Option<LitInt>,
+	migrate: Option<TypePath>,
+	requires: Vec<Path>,
+	bevy_requires: Vec<Path>,
 }
 
 impl KissingComponentArguments {
 	fn from_attr_token_stream(attr: &Attribute) -> syn::Result<Self> {
-		let mut result = Self {
-			on_construct: None,
-			on_added_to_node: None,
-		};
+		let mut result = Self::default();
 
 		attr.parse_nested_meta(|meta| {
 			if meta.path.is_ident("on_construct") {
 				result.on_construct = Some(meta.value()?.parse::<TypePath>()?);
 			} else if meta.path.is_ident("on_added_to_node") {
 				result.on_added_to_node = Some(meta.value()?.parse::<TypePath>()?);
+			} else if meta.path.is_ident("aliases") {
+				let value = meta.value()?;
+				let content;
+				syn::bracketed!(content in value);
+				result.aliases = content
+					.parse_terminated(|input| input.parse::<LitStr>(), Token![,])?
+					.into_iter()
+					.collect();
+			} else if meta.path.is_ident("version") {
+				let version = meta.value()?.parse::<LitInt>()?;
+				version.base10_parse::<u32>()?;
+				result.version = Some(version);
+			} else if meta.path.is_ident("migrate") {
+				result.migrate = Some(meta.value()?.parse::<TypePath>()?);
+			} else if meta.path.is_ident("requires") {
+				meta.parse_nested_meta(|required| {
+					result.requires.push(required.path);
+					Ok(())
+				})?;
 			} else {
 				return Err(meta.error("unknown argument for #[kissing_component]"));
 			}
 			Ok(())
 		})?;
 
+		// The migration only runs for metadata older than the component's version.
+		if let (Some(migrate), None) = (&result.migrate, &result.version) {
+			return Err(Error::new_spanned(migrate, "migrate requires a version"));
+		}
+
 		Ok(result)
 	}
+
+	/// Reads the components listed in Bevy's `#[require(...)]` so they're included in the
+	/// component's `requires` metadata.
+	fn read_bevy_requires(&mut self, attrs: &[Attribute]) -> syn::Result<()> {
+		for attr in attrs.iter().filter(|a| a.path().is_ident("require")) {
+			let requires = attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+			for required in requires {
+				// `A`, `A(...)`, `A { ... }` and `A = ...` are all valid in `#[require]`.
+				let path = match &required {
+					Expr::Path(expr) => Some(&expr.path),
+					Expr::Call(expr) => match expr.func.as_ref() {
+						Expr::Path(func) => Some(&func.path),
+						_ => None,
+					},
+					Expr::Struct(expr) => Some(&expr.path),
+					Expr::Assign(expr) => match expr.left.as_ref() {
+						Expr::Path(left) => Some(&left.path),
+						_ => None,
+					},
+					_ => None,
+				};
+				match path {
+					Some(path) => self.bevy_requires.push(path.clone()),
+					None => {
+						return Err(Error::new_spanned(
+							required,
+							"could not find the component type in #[require]",
+						));
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the `aliases`, `version` and `requires` fields of the component's
+	/// `KissingComponentData`.
+	fn generate_data_fields(&self) -> TokenStream2 {
+		let aliases = &self.aliases;
+		let version = match &self.version {
+			Some(version) => quote!(#version),
+			None => quote!(0),
+		};
+
+		// Components are referred to by their type name in the editor metadata.
+		let mut requires = vec![];
+		for path in self.bevy_requires.iter().chain(self.requires.iter()) {
+			if let Some(segment) = path.segments.last() {
+				let name = segment.ident.to_string();
+				if !requires.contains(&name) {
+					requires.push(name);
+				}
+			}
+		}
+
+		quote! {
+			aliases: &[#(#aliases),*],
+			version: #version,
+			requires: &[#(#requires),*],
+		}
+	}
+
+	/// Returns the statements that register `#[kissing_component(requires(...))]` with Bevy.
+	///
+	/// A derive can't add `#[require]` to the component, so these are registered the first time
+	/// the component is added from the editor instead. Registering again fails, which is ignored.
+	fn generate_register_required_components(&self) -> TokenStream2 {
+		let requires = &self.requires;
+		quote! {
+			#(
+				let _ = world.try_register_required_components::<Self, #requires>();
+			)*
+		}
+	}
+
+	/// Returns the calls that configure the `KissingComponent` submitted to the inventory.
+	fn generate_kissing_component_options(&self) -> TokenStream2 {
+		match &self.migrate {
+			Some(migrate) => quote!(.with_migration(#migrate)),
+			None => quote!(),
+		}
+	}
 }
 
 // -------------
@@ -48,11 +159,23 @@ impl KissingComponentArguments {
 
 /// The implementation for `#[derive(KissingComponent)]`.
 pub(super) fn kissing_component_derive_impl(input: TokenStream) -> TokenStream {
-	let struct_input = parse_macro_input!(input as ItemStruct);
+	let item = parse_macro_input!(input as Item);
+	let attrs = match &item {
+		Item::Struct(item_struct) => &item_struct.attrs,
+		Item::Enum(item_enum) => &item_enum.attrs,
+		_ => {
+			return Error::new_spanned(
+				item,
+				"KissingComponent can only be derived for structs and enums",
+			)
+			.into_compile_error()
+			.into();
+		}
+	};
 
 	// Find `#[kissing_component]` if it exists
 	let mut arguments_attribute = None;
-	for attr in &struct_input.attrs {
+	for attr in attrs {
 		if attr.path().is_ident("kissing_component") {
 			arguments_attribute = Some(attr);
 			break;
@@ -60,7 +183,7 @@ pub(super) fn kissing_component_derive_impl(input: TokenStream) -> TokenStream {
 	}
 
 	// Parse arguments from `#[kissing_component]`
-	let args = if let Some(arguments_attribute) = arguments_attribute {
+	let mut args = if let Some(arguments_attribute) = arguments_attribute {
 		match KissingComponentArguments::from_attr_token_stream(arguments_attribute) {
 			Ok(args) => args,
 			Err(err) => return err.into_compile_error().into(),
@@ -69,6 +192,22 @@ pub(super) fn kissing_component_derive_impl(input: TokenStream) -> TokenStream {
 		KissingComponentArguments::default()
 	};
 
+	// Include the components from Bevy's `#[require]` in the editor metadata
+	if let Err(err) = args.read_bevy_requires(attrs) {
+		return err.into_compile_error().into();
+	}
+
+	let struct_input = match item {
+		Item::Struct(item_struct) => item_struct,
+		Item::Enum(item_enum) => {
+			return match generate_enum_component::generate_enum_component(item_enum, args) {
+				Ok(result) => result.into(),
+				Err(err) => err.into_compile_error().into(),
+			};
+		}
+		_ => unreachable!(),
+	};
+
 	// Generate `impl` for struct this derive is on
 	let component_struct_impl =
 		generate_component_impl::generate_component_impl(struct_input.clone(), args);
@@ -1,7 +1,10 @@
 use crate::kissing_component::KissingComponentArguments;
+use crate::kissing_component::generate_godot_object_struct::is_vec;
 use crate::utils::{
-	NodeOrResource, generate_godot_object_name_for_kissing_component_data,
-	get_doc_comment_from_attrs, is_field_export, is_field_export_string, is_node_or_resource_id,
+	NodeOrResource, generate_field_initial_value,
+	generate_godot_object_name_for_kissing_component_data, get_doc_comment_from_attrs,
+	get_field_member_and_name, get_kissing_export_arguments, is_field_export,
+	is_field_export_entity, is_field_export_group, is_node_or_resource_id,
 };
 
 use proc_macro2::TokenStream as TokenStream2;
@@ -19,11 +22,20 @@ pub(super) fn generate_component_impl(
 	let component_docs = get_doc_comment_from_attrs(&struct_input.attrs);
 	let component_docs = component_docs.trim();
 
+	// Tuple-struct fields are accessed by index and named `value` or `_0`, `_1`, etc. in the editor.
+	let field_count = struct_input.fields.len();
+	let fields = struct_input
+		.fields
+		.iter()
+		.enumerate()
+		.map(|(i, f)| {
+			let (member, name) = get_field_member_and_name(i, f, field_count);
+			(f, member, name)
+		})
+		.collect::<Vec<_>>();
+
 	// Field names
-	let field_names = struct_input.fields.iter().filter_map(|f| {
-		let Some(n) = f.ident.as_ref().map(|i| i.to_string()) else {
-			return None;
-		};
+	let field_names = fields.iter().map(|(f, _, n)| {
 		let n = n.as_str();
 
 		let ty = &f.ty;
@@ -38,29 +50,50 @@ pub(super) fn generate_component_impl(
 			quote!(Some(#docs))
 		};
 
-		Some(
-			quote!(bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
-				name: #n,
-				type_string: #type_string,
-				description: #docs,
-			}),
-		)
+		// Exported fields use the editor hint of their type, such as an enum's variant names.
+		let hint = if is_field_export(f)
+			&& !is_field_export_entity(f)
+			&& is_node_or_resource_id(ty).is_none()
+		{
+			quote!(<#ty as bevy_kissing_godot::prelude::KissingComponentField>::editor_hint())
+		} else {
+			quote!(Default::default())
+		};
+
+		let export_arguments = get_kissing_export_arguments(f);
+		let renamed_from = export_arguments.renamed_from;
+
+		// Hints from `#[export(...)]`, like `range`, replace the hint of the type.
+		let hint = export_arguments.hint.unwrap_or(hint);
+
+		quote!(bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
+			name: #n,
+			type_string: #type_string,
+			description: #docs,
+			renamed_from: &[#(#renamed_from),*],
+			hint: #hint,
+			..Default::default()
+		})
 	});
 
 	// Generate field assignments used in generated [from_editor_fields].
-	let field_inputs = struct_input.fields.iter().map(|f| {
-		let Some(ident) = &f.ident else {
+	let field_inputs = fields.iter().map(|(f, member, name)| {
+		let ty = &f.ty;
+		if is_field_export_group(f) {
 			return quote! {
-				#ident: Default::default()
+				#member: <#ty as bevy_kissing_godot::prelude::KissingComponentGroup>::from_editor_fields(
+					#name,
+					&fields,
+				)
 			};
-		};
-		if !is_field_export(&f) {
+		}
+		// `#[export_entity]` fields are set once their targets have entities, see [add_component_from_editor_fields].
+		if !is_field_export(f) || is_field_export_entity(f) {
 			return quote! {
-				#ident: Default::default()
+				#member: Default::default()
 			};
 		}
 
-		let ty = &f.ty;
 		if let Some(data) = is_node_or_resource_id(ty) {
 			let (identifier, godot_type, id_type, tracker) = match data.kind {
 				NodeOrResource::Node => (
@@ -76,114 +109,254 @@ pub(super) fn generate_component_impl(
 					quote! { all_resources },
 				),
 			};
+
+			// Typed references check the class of the object, skipping it with a warning on a mismatch.
+			let (id_type, register) = match (&data.class, &data.kind) {
+				(Some(class), NodeOrResource::Node) => (
+					quote! { bevy_kissing_godot::prelude::GodotNodeRef<#class> },
+					quote! {
+						bevy_kissing_godot::prelude::GodotNodeRef::<#class>::from_export_field(
+							node, stringify!(#ident), #name, gd_object, &mut *#tracker,
+						)
+					},
+				),
+				(Some(class), NodeOrResource::Resource) => (
+					quote! { bevy_kissing_godot::prelude::GodotResourceRef<#class> },
+					quote! {
+						bevy_kissing_godot::prelude::GodotResourceRef::<#class>::from_export_field(
+							node, stringify!(#ident), #name, gd_object, &mut *#tracker,
+						)
+					},
+				),
+				(None, _) => (
+					id_type,
+					quote! { Some(#tracker.get_or_register_id_from_gd_object(&gd_object)) },
+				),
+			};
+
+			// Missing fields, type mismatches and paths without a node are left unset with a warning.
+			let field_module = quote! { bevy_kissing_godot::kissing_component::kissing_component_field };
+			let get_node = quote! {
+				#field_module::get_editor_field_node(node, stringify!(#ident), #name, &#identifier)
+			};
 			if data.is_array {
 				let convert = match data.kind {
-					NodeOrResource::Node => Some(quote! {
-						node
-							.get_node_or_null(&#identifier)
-							.map(|node_path_node|
-								#tracker.get_or_register_id_from_gd_object(&node_path_node)
-							)
-					}),
-					NodeOrResource::Resource => Some(quote! {
-						Some(#tracker.get_or_register_id_from_gd_object(&#identifier))
-					}),
+					NodeOrResource::Node => quote! {
+						#get_node.and_then(|gd_object| #register)
+					},
+					NodeOrResource::Resource => quote! {
+						{
+							let gd_object = #identifier;
+							#register
+						}
+					},
 				};
-				let to_error_string = format!(
-					"couldn't generate array field \"{}\" as `{}`",
-					ident.to_string(),
-					quote!(#ty).to_string()
-				);
 				quote! {
-					#ident: fields
-						.get(stringify!(#ident))
-						.and_then(|#identifier| {
-							let typed = #identifier
-								.try_to::<godot::prelude::Array<#godot_type>>();
-							let untyped = #identifier
-								.try_to::<godot::prelude::VarArray>();
-
-							if let Ok(typed) = typed {
-								Some(typed
-									.iter_shared()
-									.filter_map(|#identifier| #convert)
-									.collect::<Vec<#id_type>>())
-							} else if let Ok(untyped) = untyped {
-								Some(untyped
-									.iter_shared()
-									.filter_map(|#identifier| #identifier.try_to::<#godot_type>().ok())
-									.filter_map(|#identifier| #convert)
-									.collect::<Vec<#id_type>>())
-							} else {
-								None
-							}
-						})
-						.expect(#to_error_string)
+					#member: #field_module::get_editor_reference_array_field::<#godot_type>(
+						node,
+						stringify!(#ident),
+						&fields,
+						#name,
+					)
+						.into_iter()
+						.filter_map(|#identifier| #convert)
+						.collect::<Vec<#id_type>>()
 				}
 			} else {
 				let convert = match data.kind {
-					NodeOrResource::Node => Some(
-						quote! { .and_then(|#identifier| node.get_node_or_null(&#identifier)) },
-					),
+					NodeOrResource::Node => Some(quote! { .and_then(|#identifier| #get_node) }),
 					NodeOrResource::Resource => None,
 				};
 				quote! {
-					#ident: fields
-						.get(stringify!(#ident))
-						.and_then(|#identifier| {
-							#identifier
-								.try_to::<#godot_type>()
-								.ok()
-								#convert
-								.map(|#identifier| #tracker.get_or_register_id_from_gd_object(&#identifier))
-						})
+					#member: #field_module::get_editor_reference_field::<#godot_type>(
+						node,
+						stringify!(#ident),
+						&fields,
+						#name,
+					)
+						#convert
+						.and_then(|gd_object| #register)
 				}
 			}
 		} else {
-			let get_error_string = format!("could not get field of name {}", ident.to_string());
-			let to_error_string = format!(
-				"could not type field of name \"{}\" as `{:?}`",
-				ident.to_string(),
-				quote!(#ty).to_string()
-			);
-			if is_field_export_string(&f) {
+			// Missing fields and type mismatches fall back to the initial value with a warning.
+			let initial_value = generate_field_initial_value(f);
+			quote! {
+				#member: bevy_kissing_godot::kissing_component::kissing_component_field::get_editor_field::<#ty>(
+					node,
+					stringify!(#ident),
+					&fields,
+					#name,
+					|| #initial_value,
+				)
+			}
+		}
+	});
+
+	// Generate field conversions used in generated [component_to_dictionary].
+	let field_outputs = fields.iter().filter_map(|(f, member, name)| {
+		if is_field_export_group(f) {
+			return Some(quote! {
+				bevy_kissing_godot::prelude::KissingComponentGroup::to_editor_fields(
+					&c.#member,
+					#name,
+					&mut dictionary,
+				);
+			});
+		}
+		if !is_field_export(f) {
+			return None;
+		}
+
+		let value = if is_field_export_entity(f) {
+			let convert = quote! {
+				bevy_kissing_godot::kissing_component::kissing_component_field::entity_to_editor_path(world, entity, *target)
+			};
+			let value = if is_vec(&f.ty) {
 				quote! {
-					#ident: fields
-						.get(stringify!(#ident))
-						.expect(#get_error_string)
-						.try_to::<GString>()
-						.expect(#to_error_string)
-						.to_string()
+					c.#member
+						.iter()
+						.filter_map(|target| #convert)
+						.collect::<godot::prelude::Array<godot::prelude::NodePath>>()
 				}
 			} else {
+				quote! { c.#member.as_ref().and_then(|target| #convert).unwrap_or_default() }
+			};
+			quote! { godot::prelude::ToGodot::to_variant(&#value) }
+		} else if let Some(data) = is_node_or_resource_id(&f.ty) {
+			let (convert, godot_type) = match data.kind {
+				NodeOrResource::Node => (
+					quote! { bevy_kissing_godot::kissing_component::kissing_component_field::node_id_to_editor_path(world, entity, id) },
+					quote! { godot::prelude::NodePath },
+				),
+				NodeOrResource::Resource => (
+					quote! { bevy_kissing_godot::kissing_component::kissing_component_field::resource_id_to_editor_resource(world, id) },
+					quote! { godot::prelude::Gd<godot::prelude::Resource> },
+				),
+			};
+			// Typed references are converted through their untyped id.
+			let id = if data.class.is_some() {
+				quote! { let id = &id.id(); }
+			} else {
+				quote! {}
+			};
+			let value = if data.is_array {
 				quote! {
-					#ident: fields
-						.get(stringify!(#ident))
-						.expect(#get_error_string)
-						.try_to::<#ty>()
-						.expect(#to_error_string)
+					c.#member
+						.iter()
+						.filter_map(|id| { #id #convert })
+						.collect::<godot::prelude::Array<#godot_type>>()
 				}
+			} else if data.kind == NodeOrResource::Node {
+				quote! { c.#member.as_ref().and_then(|id| { #id #convert }).unwrap_or_default() }
+			} else {
+				quote! { c.#member.as_ref().and_then(|id| { #id #convert }) }
+			};
+			quote! { godot::prelude::ToGodot::to_variant(&#value) }
+		} else {
+			quote! { bevy_kissing_godot::kissing_component::kissing_component_field::field_to_editor_variant(&c.#member) }
+		};
+
+		Some(quote! {
+			dictionary.set(#name, &#value);
+		})
+	});
+
+	// Generate the `NodePath`s of `#[export_entity]` fields and the callbacks that set them
+	// once the targets have entities.
+	let entity_fields = fields
+		.iter()
+		.filter(|(f, _, _)| is_field_export_entity(f))
+		.collect::<Vec<_>>();
+	let entity_paths = entity_fields.iter().map(|(f, _, name)| {
+		let paths_ident = format_ident!("{}_paths", name);
+		let field_module =
+			quote! { bevy_kissing_godot::kissing_component::kissing_component_field };
+		let paths = if is_vec(&f.ty) {
+			quote! {
+				#field_module::get_editor_reference_array_field::<godot::prelude::NodePath>(
+					node,
+					stringify!(#ident),
+					&fields,
+					#name,
+				)
+			}
+		} else {
+			quote! {
+				#field_module::get_editor_reference_field::<godot::prelude::NodePath>(
+					node,
+					stringify!(#ident),
+					&fields,
+					#name,
+				)
+					.into_iter()
+					.collect()
 			}
+		};
+		quote! {
+			let #paths_ident: Vec<godot::prelude::NodePath> = #paths;
+		}
+	});
+	let defer_entity_references = entity_fields.iter().map(|(f, member, name)| {
+		let paths_ident = format_ident!("{}_paths", name);
+		let apply = if is_vec(&f.ty) {
+			quote! { targets.iter().flatten().copied().collect() }
+		} else {
+			quote! { targets.first().copied().flatten() }
+		};
+		quote! {
+			world
+				.non_send_resource_mut::<bevy_kissing_godot::resources::pending_entity_references::PendingEntityReferences>()
+				.defer(node, *entity, stringify!(#ident), #name, &#paths_ident, |mut e, targets| {
+					if let Some(mut c) = e.get_mut::<Self>() {
+						c.#member = #apply;
+					}
+				});
 		}
 	});
 
 	// Get the name of the Godot class used by the editor to obtain the component data.
 	let data_class_name = generate_godot_object_name_for_kissing_component_data(&ident);
 
-	// Get tokens for what happens after the construction of the component `c`.
-	let on_construct = if let Some(on_construct) = args.on_construct {
-		quote! { #on_construct(&mut c); }
-	} else {
-		quote! {}
-	};
+	// Generate the function that adds the component to an entity.
+	let add_component_from_editor_fields = generate_add_component_from_editor_fields(
+		&args,
+		quote! { #(#entity_paths)* },
+		quote! { #(#defer_entity_references)* },
+	);
 
-	// Get tokens for what happens upon adding the component `c` to a node.
-	let on_added_to_node = if let Some(on_added_to_node) = args.on_added_to_node {
-		quote! { #on_added_to_node(node, &mut c, entity, world); }
+	// Components with `#[export_resource]` fields retain their resources while on an entity.
+	let resource_fields = fields
+		.iter()
+		.filter(|(f, _, _)| {
+			is_field_export(f)
+				&& is_node_or_resource_id(&f.ty)
+					.is_some_and(|data| data.kind == NodeOrResource::Resource)
+		})
+		.map(|(_, member, _)| member)
+		.collect::<Vec<_>>();
+	let (godot_resource_holder_impl, godot_resource_holder_option) = if resource_fields.is_empty() {
+		(quote! {}, quote! {})
 	} else {
-		quote! {}
+		(
+			quote! {
+				impl bevy_kissing_godot::prelude::GodotResourceHolder for #ident {
+					fn godot_resource_ids(&self) -> Vec<bevy_kissing_godot::prelude::GodotResourceId> {
+						let mut ids = Vec::new();
+						#(
+							ids.extend(self.#resource_fields.iter().copied().map(Into::into));
+						)*
+						ids
+					}
+				}
+			},
+			quote! { .with_godot_resource_holder::<#ident>() },
+		)
 	};
 
+	let data_fields = args.generate_data_fields();
+	let kissing_component_options = args.generate_kissing_component_options();
+
 	// Add additional static fields and add [kissing_component_data] function to inventory.
 	quote! {
 		impl #ident {
@@ -194,6 +367,8 @@ pub(super) fn generate_component_impl(
 					data_class_name: #data_class_name,
 					docs: #component_docs,
 					fields: vec!(#(#field_names),*),
+					#data_fields
+					..Default::default()
 				}
 			}
 
@@ -209,32 +384,86 @@ pub(super) fn generate_component_impl(
 				}
 			}
 
-			/// Adds the component to [entity] given its fields as a map from the Godot editor UI.
-			pub fn add_component_from_editor_fields(
-				node: &mut godot::prelude::Gd<godot::prelude::Node>,
-				world: &mut bevy::prelude::World,
-				entity: &bevy::prelude::Entity,
-				fields: std::collections::BTreeMap<String, godot::prelude::Variant>,
-			) -> bool {
-				let mut system_state: bevy::ecs::system::SystemState<(
-					NonSendMut<bevy_kissing_godot::prelude::AllNodes>,
-					NonSendMut<bevy_kissing_godot::prelude::AllResources>,
-				)> = bevy::ecs::system::SystemState::new(world);
-				let (all_nodes, all_resources) = system_state.get_mut(world);
-				let mut c = Self::from_editor_fields(node, &mut all_nodes.into_inner(), &mut all_resources.into_inner(), fields);
-				#on_construct
-				#on_added_to_node
-				let Ok(mut e) = world.get_entity_mut(*entity) else { return false };
-				e.insert(c);
-				true
+			/// Converts the component on [entity] to the same format as its Godot editor fields.
+			fn component_to_dictionary(
+				world: &bevy::prelude::World,
+				entity: bevy::prelude::Entity,
+			) -> Option<godot::prelude::VarDictionary> {
+				let c = world.get::<Self>(entity)?;
+				let mut dictionary = godot::prelude::VarDictionary::new();
+				#(#field_outputs)*
+				Some(dictionary)
 			}
+
+			#add_component_from_editor_fields
 		}
 
+		#godot_resource_holder_impl
+
 		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
 			bevy_kissing_godot::kissing_component::kissing_component::KissingComponent::new(
 				#ident::kissing_component_data,
 				#ident::add_component_from_editor_fields,
 			)
+			.with_accessors::<#ident>(#ident::component_to_dictionary)
+			#godot_resource_holder_option
+			#kissing_component_options
+		}
+	}
+}
+
+/// Generates `add_component_from_editor_fields`, which builds the component with its
+/// `from_editor_fields` function and inserts it on the entity.
+///
+/// `before_construct` runs before `fields` is passed to `from_editor_fields`, and
+/// `after_insert` runs after the component `c` is inserted.
+pub(super) fn generate_add_component_from_editor_fields(
+	args: &KissingComponentArguments,
+	before_construct: TokenStream2,
+	after_insert: TokenStream2,
+) -> TokenStream2 {
+	// Get tokens for what happens after the construction of the component `c`.
+	let on_construct = if let Some(on_construct) = &args.on_construct {
+		quote! { #on_construct(&mut c); }
+	} else {
+		quote! {}
+	};
+
+	// Get tokens for what happens upon adding the component `c` to a node.
+	let on_added_to_node = if let Some(on_added_to_node) = &args.on_added_to_node {
+		quote! { #on_added_to_node(node, &mut c, entity, world); }
+	} else {
+		quote! {}
+	};
+
+	let register_required_components = args.generate_register_required_components();
+
+	quote! {
+		/// Adds the component to [entity] given its fields as a map from the Godot editor UI.
+		pub fn add_component_from_editor_fields(
+			node: &mut godot::prelude::Gd<godot::prelude::Node>,
+			world: &mut bevy::prelude::World,
+			entity: &bevy::prelude::Entity,
+			fields: std::collections::BTreeMap<String, godot::prelude::Variant>,
+		) -> Result<(), bevy_kissing_godot::kissing_component::kissing_component_error::KissingComponentError> {
+			#register_required_components
+			let mut system_state: bevy::ecs::system::SystemState<(
+				NonSendMut<bevy_kissing_godot::prelude::AllNodes>,
+				NonSendMut<bevy_kissing_godot::prelude::AllResources>,
+			)> = bevy::ecs::system::SystemState::new(world);
+			let (all_nodes, all_resources) = system_state.get_mut(world);
+			#before_construct
+			let mut c = Self::from_editor_fields(node, &mut all_nodes.into_inner(), &mut all_resources.into_inner(), fields);
+			#on_construct
+			#on_added_to_node
+			let Ok(mut e) = world.get_entity_mut(*entity) else {
+				return Err(bevy_kissing_godot::kissing_component::kissing_component_error::KissingComponentError::EntityDoesntExist {
+					node: node.to_string(),
+				});
+			};
+			e.insert(c);
+			#after_insert
+			Ok(())
 		}
 	}
 }
@@ -0,0 +1,298 @@
+use crate::kissing_component::KissingComponentArguments;
+use crate::kissing_component::generate_component_impl::generate_add_component_from_editor_fields;
+use crate::kissing_component::generate_godot_object_struct::generate_godot_object_declaration;
+use crate::utils::{
+	generate_field_editor_hint, generate_field_initial_value,
+	generate_godot_object_name_for_kissing_component_data, get_doc_comment_from_attrs,
+	get_field_member_and_name, take_kissing_export_arguments,
+};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Error, Field, Ident, ItemEnum, Member};
+
+// -----------
+// * Structs *
+// -----------
+
+/// A field of an enum component variant.
+struct VariantField<'a> {
+	field: &'a Field,
+	member: Member,
+	name: String,
+	is_export: bool,
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// Generates the component's impl and Godot object for an enum component.
+///
+/// The selected variant is stored in the `variant` editor field, and each variant's `#[export]`
+/// fields are stored as `"Variant/field"`, the same format as `#[export_group]` fields.
+pub(super) fn generate_enum_component(
+	enum_input: ItemEnum,
+	args: KissingComponentArguments,
+) -> Result<TokenStream2, Error> {
+	let ident = &enum_input.ident;
+
+	if enum_input.variants.is_empty() {
+		return Err(Error::new_spanned(
+			ident,
+			"KissingComponent can't be derived for enums without variants",
+		));
+	}
+
+	// Gather the fields of each variant.
+	let mut variants = vec![];
+	for variant in &enum_input.variants {
+		let field_count = variant.fields.len();
+		let mut fields = vec![];
+		for (i, f) in variant.fields.iter().enumerate() {
+			if let Some(attr) = f.attrs.iter().find(|a| {
+				a.path()
+					.get_ident()
+					.is_some_and(|i| i.to_string().starts_with("export_"))
+			}) {
+				return Err(Error::new_spanned(
+					attr,
+					"Only #[export] is supported on the fields of enum components",
+				));
+			}
+			// Enum components have no Godot object to report malformed `#[export]` arguments.
+			let export_attr = f.attrs.iter().find(|a| a.path().is_ident("export"));
+			if let Some(attr) = export_attr {
+				take_kissing_export_arguments(attr)?;
+			}
+			let (member, name) = get_field_member_and_name(i, f, field_count);
+			fields.push(VariantField {
+				field: f,
+				member,
+				name,
+				is_export: export_attr.is_some(),
+			});
+		}
+		variants.push((&variant.ident, variant.ident.to_string(), fields));
+	}
+
+	// The variant used by default in the editor and when the stored variant doesn't exist.
+	let default_variant_index = enum_input
+		.variants
+		.iter()
+		.position(|v| v.attrs.iter().any(|a| a.path().is_ident("default")))
+		.unwrap_or(0);
+	let default_variant_name = variants[default_variant_index].1.as_str();
+
+	let variant_names = variants
+		.iter()
+		.map(|(_, name, _)| name.as_str())
+		.collect::<Vec<&str>>();
+	let variant_hint = quote! {
+		bevy_kissing_godot::kissing_component::kissing_component_field_hint::KissingComponentFieldHint::Enum(
+			&[#(#variant_names),*]
+		)
+	};
+
+	// Variant metadata
+	let variant_data = variants.iter().map(|(_, name, fields)| {
+		let field_data = fields.iter().filter(|f| f.is_export).map(|f| {
+			let field_name = f.name.as_str();
+			let ty = &f.field.ty;
+			let hint = generate_field_editor_hint(f.field);
+			let type_string = ty.to_token_stream().to_string();
+			let docs = get_doc_comment_from_attrs(&f.field.attrs);
+			let docs = if docs.is_empty() {
+				quote!(None)
+			} else {
+				quote!(Some(#docs))
+			};
+			quote! {
+				bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
+					name: #field_name,
+					type_string: #type_string,
+					description: #docs,
+					hint: #hint,
+					..Default::default()
+				}
+			}
+		});
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentVariantData {
+				name: #name,
+				fields: vec!(#(#field_data),*),
+			}
+		}
+	});
+
+	// Variant construction used in generated [from_editor_fields].
+	let constructors = variants
+		.iter()
+		.map(|(variant_ident, name, fields)| {
+			let field_inputs = fields.iter().map(|f| {
+				let member = &f.member;
+				if !f.is_export {
+					return quote! { #member: Default::default() };
+				}
+				let ty = &f.field.ty;
+				let field_name = f.name.as_str();
+				let initial_value = generate_field_initial_value(f.field);
+				quote! {
+					#member: bevy_kissing_godot::kissing_component::kissing_component_field::get_editor_field::<#ty>(
+						node,
+						stringify!(#ident),
+						&fields,
+						&bevy_kissing_godot::kissing_component::kissing_component_data::enum_component_field_name(#name, #field_name),
+						|| #initial_value,
+					)
+				}
+			});
+			quote! { Self::#variant_ident { #(#field_inputs),* } }
+		})
+		.collect::<Vec<TokenStream2>>();
+	let default_constructor = &constructors[default_variant_index];
+	let from_editor_fields_arms = variant_names
+		.iter()
+		.zip(constructors.iter())
+		.map(|(name, constructor)| quote! { Some(#name) => #constructor, });
+
+	// Variant conversion used in generated [component_to_dictionary].
+	let to_dictionary_arms = variants.iter().map(|(variant_ident, name, fields)| {
+		let exported = fields.iter().filter(|f| f.is_export).collect::<Vec<_>>();
+		let members = exported.iter().map(|f| &f.member);
+		let bindings = (0..exported.len())
+			.map(|i| format_ident!("field_{}", i))
+			.collect::<Vec<Ident>>();
+		let field_names = exported.iter().map(|f| f.name.as_str());
+		quote! {
+			Self::#variant_ident { #(#members: #bindings,)* .. } => {
+				dictionary.set(
+					bevy_kissing_godot::kissing_component::kissing_component_data::ENUM_COMPONENT_VARIANT_FIELD,
+					#name,
+				);
+				#(
+					bevy_kissing_godot::kissing_component::kissing_component_group::set_group_field(
+						#name,
+						#field_names,
+						#bindings,
+						&mut dictionary,
+					);
+				)*
+			}
+		}
+	});
+
+	// Dynamic properties of the Godot object.
+	let variant_properties = variants.iter().flat_map(|(_, name, fields)| {
+		fields.iter().filter(|f| f.is_export).map(move |f| {
+			let hint = generate_field_editor_hint(f.field);
+			let field_name = f.name.as_str();
+			let initial_value = generate_field_initial_value(f.field);
+			quote! {
+				.with_property(
+					&bevy_kissing_godot::kissing_component::kissing_component_data::enum_component_field_name(#name, #field_name),
+					bevy_kissing_godot::kissing_component::kissing_component_field::field_to_editor_variant(&#initial_value),
+					#hint,
+				)
+			}
+		})
+	});
+
+	let component_docs = get_doc_comment_from_attrs(&enum_input.attrs);
+	let component_docs = component_docs.trim();
+	let type_string = ident.to_string();
+	let data_class_name = generate_godot_object_name_for_kissing_component_data(ident);
+	let add_component_from_editor_fields =
+		generate_add_component_from_editor_fields(&args, quote! {}, quote! {});
+	let data_fields = args.generate_data_fields();
+	let kissing_component_options = args.generate_kissing_component_options();
+
+	let object_struct = generate_godot_object_declaration(
+		ident,
+		&Ident::new(&data_class_name, Span::call_site()),
+		&[],
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_data_object::DataObjectGroupProperties::default()
+				.with_property(
+					bevy_kissing_godot::kissing_component::kissing_component_data::ENUM_COMPONENT_VARIANT_FIELD,
+					godot::prelude::ToGodot::to_variant(&godot::prelude::GString::from(#default_variant_name)),
+					#variant_hint,
+				)
+				#(#variant_properties)*
+		},
+	);
+
+	Ok(quote! {
+		impl #ident {
+			/// Returns the component's data to be used to generate the Godot editor UI.
+			fn kissing_component_data() -> bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentData {
+				bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentData {
+					name: stringify!(#ident),
+					data_class_name: #data_class_name,
+					docs: #component_docs,
+					fields: vec!(bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
+						name: bevy_kissing_godot::kissing_component::kissing_component_data::ENUM_COMPONENT_VARIANT_FIELD,
+						type_string: #type_string,
+						description: None,
+						hint: #variant_hint,
+						..Default::default()
+					}),
+					variants: vec!(#(#variant_data),*),
+					#data_fields
+					..Default::default()
+				}
+			}
+
+			/// Generates the component given a map of strings provided from the Godot editor UI.
+			fn from_editor_fields(
+				node: &godot::prelude::Gd<godot::prelude::Node>,
+				_all_nodes: &mut bevy_kissing_godot::prelude::AllNodes,
+				_all_resources: &mut bevy_kissing_godot::prelude::AllResources,
+				fields: std::collections::BTreeMap<String, godot::prelude::Variant>
+			) -> Self {
+				let variant = fields
+					.get(bevy_kissing_godot::kissing_component::kissing_component_data::ENUM_COMPONENT_VARIANT_FIELD)
+					.and_then(|variant| variant.try_to::<String>().ok());
+				match variant.as_deref() {
+					#(#from_editor_fields_arms)*
+					Some(variant) => {
+						godot::prelude::godot_warn!(
+							"{} has no variant named \"{}\", using {} instead.",
+							stringify!(#ident),
+							variant,
+							#default_variant_name
+						);
+						#default_constructor
+					}
+					None => #default_constructor,
+				}
+			}
+
+			/// Converts the component on [entity] to the same format as its Godot editor fields.
+			fn component_to_dictionary(
+				world: &bevy::prelude::World,
+				entity: bevy::prelude::Entity,
+			) -> Option<godot::prelude::VarDictionary> {
+				let c = world.get::<Self>(entity)?;
+				let mut dictionary = godot::prelude::VarDictionary::new();
+				match c {
+					#(#to_dictionary_arms)*
+				}
+				Some(dictionary)
+			}
+
+			#add_component_from_editor_fields
+		}
+
+		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
+			bevy_kissing_godot::kissing_component::kissing_component::KissingComponent::new(
+				#ident::kissing_component_data,
+				#ident::add_component_from_editor_fields,
+			)
+			.with_accessors::<#ident>(#ident::component_to_dictionary)
+			#kissing_component_options
+		}
+
+		#object_struct
+	})
+}
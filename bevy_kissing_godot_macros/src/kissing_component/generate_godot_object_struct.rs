@@ -1,9 +1,11 @@
 use proc_macro2::Span;
 use quote::{ToTokens, quote};
-use syn::{Attribute, Field, Ident, ItemStruct, Meta, Path, spanned::Spanned};
+use syn::{Attribute, Field, Fields, FieldsNamed, Ident, ItemStruct, Meta, Path, spanned::Spanned};
 
 use crate::utils::{
-	NodeOrResource, generate_godot_object_name_for_kissing_component_data, is_field_export,
+	NodeOrResource, check_enum_or_flags_field_type,
+	generate_godot_object_name_for_kissing_component_data, get_field_member_and_name,
+	is_field_export, is_field_export_group, is_node_or_resource_id, take_kissing_export_arguments,
 };
 
 // ---------
@@ -12,7 +14,7 @@ use crate::utils::{
 
 enum FieldAttribute {
 	Export {
-		export_attribute: syn::Attribute,
+		export_attribute: Box<syn::Attribute>,
 		initial_value: Option<proc_macro2::TokenStream>,
 	},
 	ExportNodeOrResource {
@@ -46,12 +48,45 @@ pub(super) fn generate_godot_object_struct(
 	// Remove attributes, they should be applied on the Component.
 	result.attrs.clear();
 
+	// Tuple-struct fields become named fields using their editor names (`value` or `_0`, `_1`, etc.).
+	if let Fields::Unnamed(unnamed) = &result.fields {
+		let field_count = unnamed.unnamed.len();
+		let named = unnamed
+			.unnamed
+			.iter()
+			.enumerate()
+			.map(|(i, f)| {
+				let (_, name) = get_field_member_and_name(i, f, field_count);
+				let mut f = f.clone();
+				f.ident = Some(Ident::new(&name, f.span()));
+				f.colon_token = Some(Default::default());
+				f
+			})
+			.collect();
+		result.fields = Fields::Named(FieldsNamed {
+			brace_token: Default::default(),
+			named,
+		});
+	}
+
+	// `#[export_group]` fields are provided as "group/field" properties by `get_property_list`.
+	let groups = result
+		.fields
+		.iter()
+		.filter(|f| is_field_export_group(f))
+		.filter_map(|f| {
+			let ident = f.ident.as_ref()?;
+			let ty = &f.ty;
+			Some(quote! { .with_group::<#ty>(stringify!(#ident)) })
+		})
+		.collect::<Vec<proc_macro2::TokenStream>>();
+
 	// Filter out all fields that aren't `#[export]`, `#[export_node]`, or `#[export_resource]`.
 	let mut fields = result
 		.fields
 		.iter()
 		.filter(|f| is_field_export(f))
-		.map(|f| f.clone())
+		.cloned()
 		.collect::<Vec<syn::Field>>();
 
 	// Update field types for Godot Object.
@@ -69,13 +104,26 @@ pub(super) fn generate_godot_object_struct(
 				export_attribute,
 				initial_value,
 			} => {
-				// Transfer `#[export]` attribute.
+				// Transfer `#[export]` attribute without the arguments gdext doesn't know about.
+				let (arguments, export_attribute) =
+					take_kissing_export_arguments(&export_attribute)?;
+				check_enum_or_flags_field_type(&arguments, &f.ty)?;
 				f.attrs.push(export_attribute);
 
-				// Add `#[init(val = TOKENS)]` for `#[initial_value = TOKENS]`.
+				// The field is edited as its `KissingComponentField::EditorType`.
+				let original_type = f.ty.clone();
+				f.ty = syn::parse_quote! {
+					<#original_type as bevy_kissing_godot::prelude::KissingComponentField>::EditorType
+				};
+
+				// Add `#[init(val = TOKENS)]` for `#[initial_value = TOKENS]`, converted to the editor type.
 				if let Some(initial_value) = initial_value {
-					f.attrs
-						.push(syn::parse_quote! { #[init(val = #initial_value)] });
+					f.attrs.push(syn::parse_quote! {
+						#[init(val = bevy_kissing_godot::prelude::KissingComponentField::to_editor_value(&{
+							let value: #original_type = Into::into(#initial_value);
+							value
+						}))]
+					});
 				}
 			}
 
@@ -84,6 +132,16 @@ pub(super) fn generate_godot_object_struct(
 			FieldAttribute::ExportNodeOrResource { data } => {
 				f.attrs.push(syn::parse_quote! { #[export] });
 
+				// `GodotNodeRef<T>` and `GodotResourceRef<T>` only accept a `T` by default.
+				let ref_class = is_node_or_resource_id(&f.ty)
+					.and_then(|info| info.class)
+					.and_then(|class| match class {
+						syn::Type::Path(class) => {
+							class.path.segments.last().map(|s| s.ident.to_string())
+						}
+						_ => None,
+					});
+
 				// Set the exported type to NodePath for nodes and Gd<Resource> for resources.
 				let original_type_is_vec = is_vec(&f.ty);
 				f.ty = match data.kind {
@@ -111,6 +169,8 @@ pub(super) fn generate_godot_object_struct(
 						.map(|n| n.to_string())
 						.collect::<Vec<String>>()
 						.join(", ")
+				} else if let Some(ref_class) = ref_class {
+					ref_class
 				} else {
 					match data.kind {
 						NodeOrResource::Node => "Node",
@@ -144,22 +204,64 @@ pub(super) fn generate_godot_object_struct(
 	}
 
 	// Append "Object" to struct identifier.
+	let component_ident = result.ident.clone();
 	result.ident = Ident::new(
 		&generate_godot_object_name_for_kissing_component_data(&result.ident),
 		Span::call_site(),
 	);
 
-	let ident = result.ident;
-	let new_declaration = quote! {
+	Ok(generate_godot_object_declaration(
+		&component_ident,
+		&result.ident,
+		&fields,
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_data_object::DataObjectGroupProperties::default()
+				#(#groups)*
+		},
+	))
+}
+
+/// Generates the declaration of the Godot object named `ident` with the `fields` and the
+/// dynamic properties from `group_properties`, an expression that returns a `DataObjectGroupProperties`.
+pub(super) fn generate_godot_object_declaration(
+	component_ident: &Ident,
+	ident: &Ident,
+	fields: &[Field],
+	group_properties: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+	quote! {
 		#[derive(godot::prelude::GodotClass)]
-		#[class(init, base = Object)]
+		#[class(init, tool, base = Object)]
 		struct #ident {
 			base: godot::prelude::Base<godot::prelude::Object>,
 			#(#fields,)*
+
+			#[init(val = #group_properties)]
+			group_properties: bevy_kissing_godot::kissing_component::kissing_component_data_object::DataObjectGroupProperties,
 		}
-	};
 
-	Ok(new_declaration)
+		#[godot::prelude::godot_api]
+		impl godot::prelude::IObject for #ident {
+			fn get_property(&self, property: godot::prelude::StringName) -> Option<godot::prelude::Variant> {
+				self.group_properties.get(&property)
+			}
+
+			fn set_property(&mut self, property: godot::prelude::StringName, value: godot::prelude::Variant) -> bool {
+				self.group_properties.set(&property, value)
+			}
+
+			fn get_property_list(&mut self) -> Vec<godot::meta::PropertyInfo> {
+				self.group_properties.property_list()
+			}
+
+			fn validate_property(&self, property: &mut godot::meta::PropertyInfo) {
+				bevy_kissing_godot::kissing_component::kissing_component_data_object::validate_data_object_property(
+					&#component_ident::kissing_component_data(),
+					property,
+				);
+			}
+		}
+	}
 }
 
 /// If a `#[export]` or `#[export_node/resource]` attribute exists on `field`, it is
@@ -192,7 +294,7 @@ fn take_any_export_attribute_if_exists(
 					FieldAttribute::ExportString { initial_value }
 				} else {
 					FieldAttribute::Export {
-						export_attribute,
+						export_attribute: Box::new(export_attribute),
 						initial_value,
 					}
 				},
@@ -256,9 +358,7 @@ fn take_export_attribute_if_exists(
 	}
 
 	// If no `#[export]` attribute, return `None` entirely.
-	let Some(export_attribute) = export_attribute else {
-		return None;
-	};
+	let export_attribute = export_attribute?;
 
 	// Extract TOKEN_STREAM from `#[initial_value = TOKEN_STREAM]` or `#[initial_value(TOKEN_STREAM)]`.
 	let mut initial_value_token_stream = None;
@@ -292,6 +392,9 @@ struct TakeExportNodeOrResourceAttributeIfExistsResult {
 /// If an `#[export_node(A, B, etc...)]` or `#[export_resource(A, B, etc...)]` attribute
 /// exists on `field`, it is removed and a `Vec` of its arguments is returned.
 ///
+/// `#[export_entity(A, B, etc...)]` is treated as `#[export_node]`, since the editor picks
+/// the entity's node.
+///
 /// Returns `Some(vec![])` if the attribute has no arguments (`#[export_node]`).
 ///
 /// Returns `None` if there are no `#[export_node]` attributes.
@@ -303,7 +406,10 @@ fn take_export_node_or_resource_attribute_if_exists(
 	let mut attrs = field.attrs.clone();
 	while i < attrs.len() {
 		let attr = &attrs[i];
-		if attr.path().is_ident("export_node") || attr.path().is_ident("export_resource") {
+		if attr.path().is_ident("export_node")
+			|| attr.path().is_ident("export_entity")
+			|| attr.path().is_ident("export_resource")
+		{
 			export_attribute = Some(attrs.remove(i));
 			break;
 		} else if export_attribute.is_none() {
@@ -346,7 +452,7 @@ fn take_export_node_or_resource_attribute_if_exists(
 		export_node_or_resource: ExportNodeOrResource {
 			types: valid_types,
 			kind: match export_attribute.path().get_ident() {
-				Some(v) if v == "export_node" => NodeOrResource::Node,
+				Some(v) if v == "export_node" || v == "export_entity" => NodeOrResource::Node,
 				_ => NodeOrResource::Resource,
 			},
 		},
@@ -354,7 +460,8 @@ fn take_export_node_or_resource_attribute_if_exists(
 	}))
 }
 
-fn is_vec(ty: &syn::Type) -> bool {
+/// Checks if the final segment of the type's path is `Vec<...>`.
+pub(super) fn is_vec(ty: &syn::Type) -> bool {
 	let syn::Type::Path(syn::TypePath { path, .. }) = ty else {
 		return false;
 	};
@@ -0,0 +1,125 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, parse_macro_input};
+
+// -------------
+// * Functions *
+// -------------
+
+/// The implementation for `#[derive(KissingComponentEnum)]`.
+pub(crate) fn kissing_component_enum_derive_impl(input: TokenStream) -> TokenStream {
+	let derive_input = parse_macro_input!(input as DeriveInput);
+
+	let Data::Enum(data_enum) = &derive_input.data else {
+		return Error::new_spanned(
+			&derive_input.ident,
+			"KissingComponentEnum can only be derived for enums",
+		)
+		.into_compile_error()
+		.into();
+	};
+
+	if let Some(variant) = data_enum
+		.variants
+		.iter()
+		.find(|v| !matches!(v.fields, Fields::Unit))
+	{
+		return Error::new_spanned(
+			variant,
+			"KissingComponentEnum can only be derived for enums without fields",
+		)
+		.into_compile_error()
+		.into();
+	}
+
+	let ident = &derive_input.ident;
+	let variant_idents = data_enum
+		.variants
+		.iter()
+		.map(|v| &v.ident)
+		.collect::<Vec<_>>();
+
+	quote! {
+		impl bevy_kissing_godot::prelude::KissingComponentEnum for #ident {
+			const VARIANT_NAMES: &'static [&'static str] = &[#(stringify!(#variant_idents)),*];
+
+			fn from_variant_name(name: &str) -> Option<Self> {
+				match name {
+					#(stringify!(#variant_idents) => Some(Self::#variant_idents),)*
+					_ => None,
+				}
+			}
+
+			fn variant_name(&self) -> &'static str {
+				match self {
+					#(Self::#variant_idents => stringify!(#variant_idents),)*
+				}
+			}
+		}
+
+		impl godot::meta::GodotConvert for #ident {
+			type Via = godot::prelude::GString;
+		}
+
+		impl godot::meta::ToGodot for #ident {
+			type Pass = godot::meta::ByValue;
+
+			fn to_godot(&self) -> Self::Via {
+				godot::prelude::GString::from(
+					bevy_kissing_godot::prelude::KissingComponentEnum::variant_name(self),
+				)
+			}
+		}
+
+		impl godot::meta::FromGodot for #ident {
+			fn try_from_godot(via: Self::Via) -> Result<Self, godot::meta::error::ConvertError> {
+				Ok(bevy_kissing_godot::kissing_component::kissing_component_enum::enum_from_editor_field::<Self>(
+					&godot::prelude::ToGodot::to_variant(&via),
+				))
+			}
+		}
+
+		impl godot::register::property::Var for #ident {
+			fn get_property(&self) -> Self::Via {
+				godot::meta::ToGodot::to_godot(self)
+			}
+
+			fn set_property(&mut self, value: Self::Via) {
+				*self = godot::meta::FromGodot::from_godot(value);
+			}
+
+			fn var_hint() -> godot::meta::PropertyHintInfo {
+				<Self as bevy_kissing_godot::prelude::KissingComponentEnum>::property_hint()
+			}
+		}
+
+		impl godot::register::property::Export for #ident {}
+
+		impl bevy_kissing_godot::prelude::KissingComponentField for #ident {
+			type EditorType = godot::prelude::GString;
+
+			fn from_editor_value(value: godot::prelude::GString) -> Self {
+				bevy_kissing_godot::kissing_component::kissing_component_enum::enum_from_editor_field::<Self>(
+					&godot::prelude::ToGodot::to_variant(&value),
+				)
+			}
+
+			fn to_editor_value(&self) -> godot::prelude::GString {
+				godot::prelude::GString::from(
+					bevy_kissing_godot::prelude::KissingComponentEnum::variant_name(self),
+				)
+			}
+
+			fn from_editor_variant(value: &godot::prelude::Variant) -> Option<Self> {
+				Some(bevy_kissing_godot::kissing_component::kissing_component_enum::enum_from_editor_field::<Self>(value))
+			}
+
+			fn editor_hint() -> bevy_kissing_godot::kissing_component::kissing_component_field_hint::KissingComponentFieldHint {
+				bevy_kissing_godot::kissing_component::kissing_component_field_hint::KissingComponentFieldHint::Enum(
+					<Self as bevy_kissing_godot::prelude::KissingComponentEnum>::VARIANT_NAMES,
+				)
+			}
+		}
+	}
+	.into()
+}
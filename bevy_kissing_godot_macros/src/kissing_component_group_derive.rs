@@ -0,0 +1,127 @@
+use crate::utils::{
+	generate_field_editor_hint, get_doc_comment_from_attrs, take_kissing_export_arguments,
+};
+
+use proc_macro::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Error, Fields, ItemStruct, parse_macro_input};
+
+// -------------
+// * Functions *
+// -------------
+
+/// The implementation for `#[derive(KissingComponentGroup)]`.
+pub(crate) fn kissing_component_group_derive_impl(input: TokenStream) -> TokenStream {
+	let item_struct = parse_macro_input!(input as ItemStruct);
+
+	let Fields::Named(fields) = &item_struct.fields else {
+		return Error::new_spanned(
+			&item_struct.fields,
+			"KissingComponentGroup can only be derived for structs with named fields",
+		)
+		.into_compile_error()
+		.into();
+	};
+
+	let exported_fields = fields
+		.named
+		.iter()
+		.filter(|f| f.attrs.iter().any(|a| a.path().is_ident("export")))
+		.collect::<Vec<&syn::Field>>();
+
+	// Groups have no Godot object to report malformed `#[export]` arguments.
+	for attr in exported_fields
+		.iter()
+		.flat_map(|f| f.attrs.iter().filter(|a| a.path().is_ident("export")))
+	{
+		if let Err(err) = take_kissing_export_arguments(attr) {
+			return err.into_compile_error().into();
+		}
+	}
+
+	let field_data = exported_fields.iter().map(|f| {
+		let name = f.ident.as_ref().map(|i| i.to_string()).unwrap_or_default();
+		let ty = &f.ty;
+		let hint = generate_field_editor_hint(f);
+		let type_string = ty.to_token_stream().to_string();
+		let docs = get_doc_comment_from_attrs(&f.attrs);
+		let docs = if docs.is_empty() {
+			quote!(None)
+		} else {
+			quote!(Some(#docs))
+		};
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
+				name: #name,
+				type_string: #type_string,
+				description: #docs,
+				hint: #hint,
+				..Default::default()
+			}
+		}
+	});
+
+	let from_editor_fields_body = if exported_fields.is_empty() {
+		quote! {
+			let _ = (prefix, fields);
+			Self::default()
+		}
+	} else {
+		let field_inputs = exported_fields.iter().map(|f| {
+			let field_ident = &f.ident;
+			let ty = &f.ty;
+			quote! {
+				if let Some(value) = bevy_kissing_godot::kissing_component::kissing_component_group::get_group_field::<#ty>(
+					prefix,
+					stringify!(#field_ident),
+					fields,
+				) {
+					result.#field_ident = value;
+				}
+			}
+		});
+		quote! {
+			let mut result = Self::default();
+			#(#field_inputs)*
+			result
+		}
+	};
+
+	let field_outputs = exported_fields.iter().map(|f| {
+		let field_ident = &f.ident;
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_group::set_group_field(
+				prefix,
+				stringify!(#field_ident),
+				&self.#field_ident,
+				fields,
+			);
+		}
+	});
+	let to_editor_fields_body = if exported_fields.is_empty() {
+		quote!(let _ = (prefix, fields);)
+	} else {
+		quote!(#(#field_outputs)*)
+	};
+
+	let ident = &item_struct.ident;
+	quote! {
+		impl bevy_kissing_godot::prelude::KissingComponentGroup for #ident {
+			fn group_fields() -> Vec<bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData> {
+				vec!(#(#field_data),*)
+			}
+
+			fn from_editor_fields(
+				prefix: &str,
+				fields: &std::collections::BTreeMap<String, godot::prelude::Variant>,
+			) -> Self {
+				#from_editor_fields_body
+			}
+
+			fn to_editor_fields(&self, prefix: &str, fields: &mut godot::prelude::VarDictionary) {
+				#to_editor_fields_body
+			}
+		}
+	}
+	.into()
+}
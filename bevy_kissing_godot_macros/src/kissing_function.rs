@@ -0,0 +1,199 @@
+use crate::utils::get_doc_comment_from_attrs;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+	Error, FnArg, GenericArgument, ItemFn, Pat, PathArguments, Type, parse_macro_input,
+	spanned::Spanned,
+};
+
+// -----------
+// * Structs *
+// -----------
+
+/// The `In<T>` argument of a `#[kissing_function]` system.
+struct FunctionInput {
+	/// The `T` in `In<T>`.
+	ty: Type,
+
+	/// The name and type of each argument passed from Godot.
+	/// A tuple input is passed as multiple arguments.
+	arguments: Vec<(String, Type)>,
+
+	is_tuple: bool,
+}
+
+// -------------
+// * Functions *
+// -------------
+
+pub(crate) fn kissing_function_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+	if !attr.is_empty() {
+		return Error::new(
+			proc_macro2::TokenStream::from(attr).span(),
+			"#[kissing_function] does not take any arguments",
+		)
+		.into_compile_error()
+		.into();
+	}
+
+	let input_fn = parse_macro_input!(item as ItemFn);
+	let input = match parse_function_input(&input_fn) {
+		Ok(input) => input,
+		Err(e) => return e.into_compile_error().into(),
+	};
+
+	let ident = &input_fn.sig.ident;
+	let docs = get_doc_comment_from_attrs(&input_fn.attrs);
+	let docs = docs.trim();
+
+	let (argument_names, call) = match input {
+		Some(input) => {
+			let argument_count = input.arguments.len();
+			let argument_names = input
+				.arguments
+				.iter()
+				.map(|(name, _)| name.clone())
+				.collect::<Vec<String>>();
+			let arguments = input
+				.arguments
+				.iter()
+				.enumerate()
+				.map(|(index, (_, ty))| {
+					quote! {
+						bevy_kissing_godot::kissing_function::kissing_function::get_argument::<#ty>(args, #index)?
+					}
+				})
+				.collect::<Vec<TokenStream2>>();
+			let input_expr = if input.is_tuple {
+				quote!((#(#arguments,)*))
+			} else {
+				quote!(#(#arguments)*)
+			};
+			let input_ty = input.ty;
+			(
+				argument_names,
+				quote! {
+					bevy_kissing_godot::kissing_function::kissing_function::expect_argument_count(args, #argument_count)?;
+					let input = #input_expr;
+					bevy_kissing_godot::kissing_function::kissing_function::run_one_shot_system::<bevy_kissing_godot::kissing_function::kissing_function::In<#input_ty>, _, _, _>(world, #ident, input)
+				},
+			)
+		}
+		None => (
+			vec![],
+			quote! {
+				bevy_kissing_godot::kissing_function::kissing_function::expect_argument_count(args, 0)?;
+				bevy_kissing_godot::kissing_function::kissing_function::run_one_shot_system::<(), _, _, _>(world, #ident, ())
+			},
+		),
+	};
+
+	quote! {
+		#input_fn
+
+		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
+			bevy_kissing_godot::kissing_function::kissing_function::KissingFunction::new(
+				|| bevy_kissing_godot::kissing_function::kissing_function_data::KissingFunctionData {
+					name: stringify!(#ident),
+					argument_names: &[#(#argument_names),*],
+					docs: #docs,
+				},
+				|world, args| {
+					#call
+				},
+			)
+		}
+	}
+	.into()
+}
+
+/// Finds the first `In<T>` parameter of the system, if there is one.
+fn parse_function_input(input_fn: &ItemFn) -> syn::Result<Option<FunctionInput>> {
+	let Some(FnArg::Typed(pat_type)) = input_fn.sig.inputs.first() else {
+		return Ok(None);
+	};
+	let Some(ty) = get_in_type(&pat_type.ty) else {
+		return Ok(None);
+	};
+
+	// Extract `x` from the `In(x)` pattern.
+	let inner_pat = match pat_type.pat.as_ref() {
+		Pat::TupleStruct(pat_tuple_struct) if pat_tuple_struct.elems.len() == 1 => {
+			Some(&pat_tuple_struct.elems[0])
+		}
+		_ => None,
+	};
+
+	let arguments = match &ty {
+		Type::Tuple(type_tuple) => {
+			let names = match inner_pat {
+				Some(Pat::Tuple(pat_tuple)) => pat_tuple
+					.elems
+					.iter()
+					.map(get_pattern_name)
+					.collect::<Vec<_>>(),
+				_ => vec![],
+			};
+			type_tuple
+				.elems
+				.iter()
+				.enumerate()
+				.map(|(index, ty)| {
+					let name = names
+						.get(index)
+						.cloned()
+						.flatten()
+						.unwrap_or_else(|| format!("arg{}", index));
+					(name, ty.clone())
+				})
+				.collect()
+		}
+		_ => {
+			let name = inner_pat
+				.and_then(get_pattern_name)
+				.or_else(|| get_pattern_name(&pat_type.pat))
+				.unwrap_or_else(|| "arg0".to_string());
+			vec![(name, ty.clone())]
+		}
+	};
+
+	Ok(Some(FunctionInput {
+		is_tuple: matches!(ty, Type::Tuple(_)),
+		ty,
+		arguments,
+	}))
+}
+
+/// Returns `T` if `ty` is `In<T>`.
+fn get_in_type(ty: &Type) -> Option<Type> {
+	let Type::Path(type_path) = ty else {
+		return None;
+	};
+	let segment = type_path.path.segments.last()?;
+	if segment.ident != "In" {
+		return None;
+	}
+	let PathArguments::AngleBracketed(args) = &segment.arguments else {
+		return None;
+	};
+	match args.args.first()? {
+		GenericArgument::Type(ty) => Some(ty.clone()),
+		_ => None,
+	}
+}
+
+/// Returns the identifier bound by `pat` without any `_` prefix.
+fn get_pattern_name(pat: &Pat) -> Option<String> {
+	let Pat::Ident(pat_ident) = pat else {
+		return None;
+	};
+	let name = pat_ident.ident.to_string();
+	let name = name.trim_start_matches('_');
+	if name.is_empty() {
+		None
+	} else {
+		Some(name.to_string())
+	}
+}
@@ -0,0 +1,152 @@
+use crate::utils::get_doc_comment_from_attrs;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{ToTokens, quote};
+use syn::{Error, Fields, ItemStruct, parse_macro_input};
+
+// -------------
+// * Functions *
+// -------------
+
+/// The implementation for `#[derive(KissingResource)]`.
+pub(crate) fn kissing_resource_derive_impl(input: TokenStream) -> TokenStream {
+	let item_struct = parse_macro_input!(input as ItemStruct);
+
+	let exported_fields = match &item_struct.fields {
+		Fields::Named(fields) => fields
+			.named
+			.iter()
+			.filter(|f| f.attrs.iter().any(|a| a.path().is_ident("export")))
+			.collect::<Vec<&syn::Field>>(),
+		Fields::Unit => vec![],
+		Fields::Unnamed(_) => {
+			return Error::new_spanned(
+				&item_struct.fields,
+				"KissingResource can only be derived for unit structs or structs with named fields",
+			)
+			.into_compile_error()
+			.into();
+		}
+	};
+
+	generate_resource_impl(&item_struct, exported_fields).into()
+}
+
+fn generate_resource_impl(item_struct: &ItemStruct, fields: Vec<&syn::Field>) -> TokenStream2 {
+	let ident = &item_struct.ident;
+	let docs = get_doc_comment_from_attrs(&item_struct.attrs);
+	let docs = docs.trim();
+
+	let field_data = fields.iter().map(|f| {
+		let name = f.ident.as_ref().map(|i| i.to_string()).unwrap_or_default();
+		let type_string = f.ty.to_token_stream().to_string();
+		let docs = get_doc_comment_from_attrs(&f.attrs);
+		let docs = if docs.is_empty() {
+			quote!(None)
+		} else {
+			quote!(Some(#docs))
+		};
+		quote! {
+			bevy_kissing_godot::kissing_component::kissing_component_data::KissingComponentFieldData {
+				name: #name,
+				type_string: #type_string,
+				description: #docs,
+				..Default::default()
+			}
+		}
+	});
+
+	let field_outputs = fields.iter().map(|f| {
+		let field_ident = &f.ident;
+		quote! {
+			dictionary.set(
+				stringify!(#field_ident),
+				&bevy_kissing_godot::kissing_component::kissing_component_field::field_to_editor_variant(&resource.#field_ident),
+			);
+		}
+	});
+
+	let field_setters = fields.iter().map(|f| {
+		let field_ident = &f.ident;
+		let ty = &f.ty;
+		quote! {
+			stringify!(#field_ident) => match bevy_kissing_godot::kissing_component::kissing_component_field::field_from_editor_variant::<#ty>(value) {
+				Some(value) => {
+					resource.#field_ident = value;
+					true
+				}
+				None => false,
+			}
+		}
+	});
+
+	// Resources without exported fields are only exposed for `bevy_resource_changed`.
+	let (to_dictionary_body, set_field_body) = if fields.is_empty() {
+		(
+			quote! {
+				world.get_resource::<Self>()?;
+				Some(godot::prelude::VarDictionary::new())
+			},
+			quote! {
+				let _ = (world, field, value);
+				false
+			},
+		)
+	} else {
+		(
+			quote! {
+				let resource = world.get_resource::<Self>()?;
+				let mut dictionary = godot::prelude::VarDictionary::new();
+				#(#field_outputs)*
+				Some(dictionary)
+			},
+			quote! {
+				let Some(mut resource) = world.get_resource_mut::<Self>() else {
+					return false;
+				};
+				match field {
+					#(#field_setters,)*
+					_ => false,
+				}
+			},
+		)
+	};
+
+	quote! {
+		impl #ident {
+			/// Returns the resource's data to be used by GDScript and the Godot editor.
+			fn kissing_resource_data() -> bevy_kissing_godot::kissing_resource::kissing_resource_data::KissingResourceData {
+				bevy_kissing_godot::kissing_resource::kissing_resource_data::KissingResourceData {
+					name: stringify!(#ident),
+					docs: #docs,
+					fields: vec!(#(#field_data),*),
+				}
+			}
+
+			/// Converts the resource's exported fields to a dictionary.
+			fn resource_to_dictionary(
+				world: &bevy::prelude::World,
+			) -> Option<godot::prelude::VarDictionary> {
+				#to_dictionary_body
+			}
+
+			/// Sets the exported field [field] of the resource to [value].
+			fn set_resource_field(
+				world: &mut bevy::prelude::World,
+				field: &str,
+				value: &godot::prelude::Variant,
+			) -> bool {
+				#set_field_body
+			}
+		}
+
+		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
+			bevy_kissing_godot::kissing_resource::kissing_resource::KissingResource::new::<#ident>(
+				#ident::kissing_resource_data,
+				#ident::resource_to_dictionary,
+				#ident::set_resource_field,
+			)
+		}
+	}
+}
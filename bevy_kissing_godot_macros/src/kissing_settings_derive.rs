@@ -0,0 +1,159 @@
+use crate::utils::{
+	generate_field_initial_value, get_doc_comment_from_attrs, take_kissing_export_arguments,
+};
+
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Attribute, Error, Fields, ItemStruct, LitStr, parse_macro_input};
+
+// -----------
+// * Structs *
+// -----------
+
+/// A representation of the arguments passed to `#[kissing_settings]`.
+#[derive(Default)]
+struct KissingSettingsArguments {
+	prefix: Option<String>,
+	reload_on_change: bool,
+}
+
+impl KissingSettingsArguments {
+	fn from_attr_token_stream(attr: &Attribute) -> syn::Result<Self> {
+		let mut result = Self::default();
+
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("prefix") {
+				let prefix = meta.value()?.parse::<LitStr>()?.value();
+				result.prefix = Some(prefix.trim_end_matches('/').to_string());
+			} else if meta.path.is_ident("reload_on_change") {
+				result.reload_on_change = true;
+			} else {
+				return Err(meta.error("unknown argument for #[kissing_settings]"));
+			}
+			Ok(())
+		})?;
+
+		Ok(result)
+	}
+}
+
+// -------------
+// * Functions *
+// -------------
+
+/// The implementation for `#[derive(KissingSettings)]`.
+pub(crate) fn kissing_settings_derive_impl(input: TokenStream) -> TokenStream {
+	let item_struct = parse_macro_input!(input as ItemStruct);
+
+	let args = match item_struct
+		.attrs
+		.iter()
+		.find(|a| a.path().is_ident("kissing_settings"))
+	{
+		Some(attr) => match KissingSettingsArguments::from_attr_token_stream(attr) {
+			Ok(args) => args,
+			Err(err) => return err.into_compile_error().into(),
+		},
+		None => KissingSettingsArguments::default(),
+	};
+
+	let Fields::Named(fields) = &item_struct.fields else {
+		return Error::new_spanned(
+			&item_struct.fields,
+			"KissingSettings can only be derived for structs with named fields",
+		)
+		.into_compile_error()
+		.into();
+	};
+
+	let ident = &item_struct.ident;
+	let docs = get_doc_comment_from_attrs(&item_struct.attrs);
+	let docs = docs.trim();
+	let reload_on_change = args.reload_on_change;
+	let prefix = args
+		.prefix
+		.unwrap_or_else(|| ident.to_string().to_case(Case::Snake));
+
+	let mut field_data = vec![];
+	let mut field_inputs = vec![];
+	let mut field_updates = vec![];
+	for f in &fields.named {
+		let Some(field_ident) = &f.ident else {
+			continue;
+		};
+		let Some(export_attr) = f.attrs.iter().find(|a| a.path().is_ident("export")) else {
+			field_inputs.push(quote!(#field_ident: Default::default()));
+			continue;
+		};
+
+		// There's no Godot object to report malformed arguments, so they're reported here.
+		let hint = match take_kissing_export_arguments(export_attr) {
+			Ok((arguments, _)) => arguments.hint.unwrap_or_else(|| {
+				let ty = &f.ty;
+				quote!(<#ty as bevy_kissing_godot::prelude::KissingComponentField>::editor_hint())
+			}),
+			Err(err) => return err.into_compile_error().into(),
+		};
+
+		let name = field_ident.to_string();
+		let key = format!("{}/{}", prefix, name);
+		let ty = &f.ty;
+		let initial_value = generate_field_initial_value(f);
+
+		field_data.push(quote! {
+			bevy_kissing_godot::kissing_settings::kissing_settings_data::KissingSettingsFieldData {
+				name: #name,
+				key: #key,
+				default_value: bevy_kissing_godot::kissing_component::kissing_component_field::field_to_editor_variant(&#initial_value),
+				hint: #hint,
+			}
+		});
+		let read_setting = quote! {
+			bevy_kissing_godot::kissing_settings::kissing_settings::get_project_setting::<#ty>(
+				#key,
+				#initial_value,
+				#hint,
+			)
+		};
+		field_inputs.push(quote!(#field_ident: #read_setting));
+		field_updates.push(quote!(settings.#field_ident = #read_setting;));
+	}
+
+	quote! {
+		impl #ident {
+			/// Returns the settings' data used to register them in `ProjectSettings`.
+			fn kissing_settings_data() -> bevy_kissing_godot::kissing_settings::kissing_settings_data::KissingSettingsData {
+				bevy_kissing_godot::kissing_settings::kissing_settings_data::KissingSettingsData {
+					name: stringify!(#ident),
+					docs: #docs,
+					reload_on_change: #reload_on_change,
+					fields: vec!(#(#field_data),*),
+				}
+			}
+
+			/// Reads the resource from `ProjectSettings` and inserts it into [world].
+			///
+			/// If the resource already exists, only its exported fields are updated, so the
+			/// state in its other fields is kept when the settings are reloaded.
+			#[allow(unused_mut, unused_variables)] // For resources without exported fields.
+			fn insert_from_project_settings(world: &mut bevy::prelude::World) {
+				if let Some(mut settings) = world.get_resource_mut::<Self>() {
+					#(#field_updates)*
+				} else {
+					world.insert_resource(Self {
+						#(#field_inputs),*
+					});
+				}
+			}
+		}
+
+		bevy_kissing_godot::prelude::bevy_kissing_godot_inventory::submit! {
+			bevy_kissing_godot::kissing_settings::kissing_settings::KissingSettings::new(
+				#ident::kissing_settings_data,
+				#ident::insert_from_project_settings,
+			)
+		}
+	}
+	.into()
+}
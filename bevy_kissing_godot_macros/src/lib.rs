@@ -6,10 +6,16 @@ use proc_macro::TokenStream;
 
 mod arguments;
 mod get_compilation_timestamp;
+mod godot_group_marker_derive;
 mod kiss_bevy;
 mod kissing_component;
+mod kissing_component_enum_derive;
+mod kissing_component_group_derive;
 mod kissing_event_derive;
+mod kissing_function;
 mod kissing_node_derive;
+mod kissing_resource_derive;
+mod kissing_settings_derive;
 mod plugin_and_kissing_component;
 mod utils;
 
@@ -23,7 +29,7 @@ mod utils;
 /// ```rust
 /// #[derive(Component, KissingComponent)]
 /// struct Health {
-/// 	maximum: i32,
+///     maximum: i32,
 /// }
 /// ```
 ///
@@ -32,9 +38,39 @@ mod utils;
 /// ```rust
 /// #[derive(Component, KissingComponent)]
 /// struct Health {
-/// 	#[export]
-/// 	#[initial_value = 100]
-/// 	maximum: i32,
+///     #[export]
+///     #[initial_value = 100]
+///     maximum: i32,
+/// }
+/// ```
+///
+/// The gdext `range`, `enum`, `flags`, `file`, `dir`, `multiline` and `color_no_alpha` arguments
+/// of `#[export(...)]` are also applied to the values when the component is built, so values
+/// saved outside the editor's constraints are clamped or fixed.
+///
+/// ## Exporting Custom Types
+///
+/// Any field type implementing [`KissingComponentField`] can be `#[export]`ed. The field is
+/// edited as its `KissingComponentField::EditorType` and converted back when the component is built.
+/// ```rust
+/// struct Meters(f32);
+///
+/// impl KissingComponentField for Meters {
+///     type EditorType = f32;
+///
+///     fn from_editor_value(value: f32) -> Self {
+///         Meters(value)
+///     }
+///
+///     fn to_editor_value(&self) -> f32 {
+///         self.0
+///     }
+/// }
+///
+/// #[derive(Component, KissingComponent)]
+/// struct Jump {
+///     #[export]
+///     height: Meters,
 /// }
 /// ```
 ///
@@ -53,20 +89,31 @@ mod utils;
 /// ```rust
 /// #[derive(Component, KissingComponent)]
 /// struct Health {
-/// 	#[export(initial_value = 100)]
-/// 	maximum: i32,
+///     #[export(initial_value = 100)]
+///     maximum: i32,
 ///
-/// 	#[export_node(Label)]
-/// 	label: Option<GodotNodeId>,
+///     #[export_node(Label)]
+///     label: Option<GodotNodeId>,
 /// }
 ///
 /// fn on_update(
-///		healths: Query<&Health>,
-///		all_nodes: NonSend<AllNodes>,
+///     healths: Query<&Health>,
+///     all_nodes: NonSend<AllNodes>,
 /// ) {
-/// 	for health in gooblers.iter() {
-/// 		let mut health_label: Gd<Label> = health.label.get_as::<Label>(&all_nodes);
-/// 	}
+///     for health in gooblers.iter() {
+///         let mut health_label: Gd<Label> = health.label.get_as::<Label>(&all_nodes);
+///     }
+/// }
+/// ```
+///
+/// The field may also be an `Option<GodotNodeRef<T>>`. The node is checked to be a `T` when
+/// the component is built, so `get` returns a `Gd<T>` without a cast. A node of another class
+/// produces a warning and `None`. Without arguments, the editor only accepts a `T`.
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// struct Health {
+///     #[export_node]
+///     label: Option<GodotNodeRef<Label>>,
 /// }
 /// ```
 ///
@@ -76,6 +123,100 @@ mod utils;
 ///
 /// The field it's used on must be an [`Option<bevy_kissing_godot::prelude::GodotResourceId>`].
 /// [`GodotResourceId`] can be converted an actual `Gd<T>` node through `NonSend<AllResources>` at runtime.
+/// `Option<GodotResourceRef<T>>` is checked the same way as `GodotNodeRef<T>`.
+///
+/// The component implements `GodotResourceHolder`, so its resources stay loaded while it's on
+/// an entity and can be freed once it's removed.
+///
+/// ## Exporting Entities
+///
+/// `#[export_entity]` takes the same arguments as `#[export_node]`, but the field must be an
+/// [`Option<bevy::prelude::Entity>`] or `Vec<Entity>`. The node picked in the editor is resolved
+/// to its entity once every node being set up with it has one, so sibling nodes that are
+/// registered later can still be referenced. Paths that don't resolve produce a warning and `None`.
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// struct FollowTarget {
+///     #[export_entity(Node3D)]
+///     target: Option<Entity>,
+/// }
+/// ```
+///
+/// ## Tuple Structs and Enums
+///
+/// Fields of tuple structs are named `value` in the editor if there is only one, otherwise
+/// `_0`, `_1`, etc.
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// struct Speed(#[export] f32);
+/// ```
+///
+/// Enum components show their variants as a dropdown named `variant`, followed by the `#[export]`
+/// fields of every variant as `"Variant/field"`. The `#[default]` variant (or the first one)
+/// is used if the stored variant doesn't exist. Enum fields only support `#[export]`.
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// enum Movement {
+///     Idle,
+///     Walk {
+///         #[export]
+///         #[initial_value = 4.0]
+///         speed: f32,
+///     },
+///     Fly(#[export] f32),
+/// }
+/// ```
+///
+/// ## Exporting Groups
+///
+/// `#[export_group]` nests a struct that derives [`KissingComponentGroup`]. Its exported fields
+/// are shown as a group in the editor and are saved as `"field/subfield"` entries.
+///
+/// ## Renaming and Migrating
+///
+/// Component data is saved in scenes by component and field name. To rename either without
+/// breaking saved scenes, list the old names:
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// #[kissing_component(aliases = ["Hp"])]
+/// struct Health {
+///     #[export(rename_from = "max")]
+///     maximum: i32,
+/// }
+/// ```
+///
+/// For other changes, set a `version` and a `migrate` function. It's called with the version the
+/// data was saved with (`0` for data saved before versions were used) whenever it's older than `version`.
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// #[kissing_component(version = 1, migrate = migrate_health)]
+/// struct Health {
+///     #[export]
+///     maximum: f32,
+/// }
+///
+/// fn migrate_health(from_version: u32, fields: &mut BTreeMap<String, Variant>) {
+///     if from_version < 1 {
+///         // `maximum` used to be stored as a percentage.
+///         if let Some(maximum) = fields.get("maximum").and_then(|v| v.try_to::<f32>().ok()) {
+///             fields.insert("maximum".to_string(), (maximum * 100.0).to_variant());
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Required Components
+///
+/// Components listed in Bevy's `#[require]` are included in the editor metadata, and are added
+/// before this component when the editor sets them, so they keep their editor values:
+/// ```rust
+/// #[derive(Component, KissingComponent)]
+/// #[require(Health, Team)]
+/// struct Enemy;
+/// ```
+///
+/// `#[kissing_component(requires(Health, Team))]` does the same, but registers the requirements
+/// with Bevy the first time the component is added from the editor.
 ///
 /// ## Field Attribute Behavior
 ///
@@ -87,6 +228,8 @@ mod utils;
 	KissingComponent,
 	attributes(
 		kissing_component,
+		export_entity,
+		export_group,
 		export_resource,
 		export_node,
 		export,
@@ -105,6 +248,55 @@ pub fn get_compilation_timestamp(input: TokenStream) -> TokenStream {
 	get_compilation_timestamp::get_compilation_timestamp_impl(input)
 }
 
+/// Allows a fieldless enum to be `#[export]`ed in a [`KissingComponent`].
+///
+/// The component editor shows the variants as a dropdown and stores the selected variant's
+/// name. If a saved variant was renamed or removed, a warning is printed and the enum's
+/// `Default` is used.
+/// ```rust
+/// #[derive(Default, KissingComponentEnum)]
+/// enum AiMode {
+///     #[default]
+///     Idle,
+///     Patrol,
+///     Chase,
+/// }
+///
+/// #[derive(Component, KissingComponent)]
+/// struct Enemy {
+///     #[export]
+///     mode: AiMode,
+/// }
+/// ```
+#[proc_macro_derive(KissingComponentEnum)]
+pub fn kissing_component_enum_derive(input: TokenStream) -> TokenStream {
+	kissing_component_enum_derive::kissing_component_enum_derive_impl(input)
+}
+
+/// Allows a small struct to be nested in a [`KissingComponent`] with `#[export_group]`.
+///
+/// The struct's `#[export]` fields are shown as a collapsible group in the component editor.
+/// Fields that aren't exported, or are missing from the saved data, use the struct's `Default`.
+/// ```rust
+/// #[derive(Default, KissingComponentGroup)]
+/// struct MovementConfig {
+///     #[export]
+///     speed: f32,
+///     #[export]
+///     jump_height: f32,
+/// }
+///
+/// #[derive(Component, KissingComponent)]
+/// struct Player {
+///     #[export_group]
+///     movement: MovementConfig,
+/// }
+/// ```
+#[proc_macro_derive(KissingComponentGroup, attributes(export))]
+pub fn kissing_component_group_derive(input: TokenStream) -> TokenStream {
+	kissing_component_group_derive::kissing_component_group_derive_impl(input)
+}
+
 /// Used to mark the entry function for a Bevy💋Godot app.
 ///
 /// This attribute requires one argument for the name of the Bevy app node that needs
@@ -112,7 +304,7 @@ pub fn get_compilation_timestamp(input: TokenStream) -> TokenStream {
 /// ```rust
 /// #[kiss_bevy(node_name = MyAppNodeName)]
 /// fn main(app: &mut bevy::prelude::App) {
-///		// Do stuff with `app`...
+///     // Do stuff with `app`...
 /// }
 /// ```
 ///
@@ -121,28 +313,28 @@ pub fn get_compilation_timestamp(input: TokenStream) -> TokenStream {
 /// desired macros can be assigned to `process_wrapper` or `physics_process_wrapper` arguments.
 ///
 /// These macros should take two arguments:
-/// 	* The first is the original expression
-/// 	* The second is the `self` expression
+///     * The first is the original expression
+///     * The second is the `self` expression
 ///
 /// For example:
 /// ```rust
 /// macro_rules panic_catcher {
-/// 	($process: expr, $self: expr) => {
-///			let result = std::panic::catch_unwind(|| {
-/// 			$process
-/// 		});
-/// 		if result.is_err() {
-/// 			println!("Panic happened!");
+///     ($process: expr, $self: expr) => {
+///         let result = std::panic::catch_unwind(|| {
+///             $process
+///         });
+///         if result.is_err() {
+///             println!("Panic happened!");
 ///
-/// 			// Check bevy_kissing_godot::kissing_app for all `self.app` functions.
-/// 			$self.app.clear_app();
-/// 		}
-/// 	}
+///             // Check bevy_kissing_godot::kissing_app for all `self.app` functions.
+///             $self.app.clear_app();
+///         }
+///     }
 /// }
 ///
 /// #[kiss_bevy(node_name = MyAppNodeName, process_wrapper = panic_catcher, physics_process_wrapper = panic_catcher)]
 /// fn main(app: &mut bevy::prelude::App) {
-///		// Do stuff with `app`...
+///     // Do stuff with `app`...
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -159,6 +351,81 @@ pub fn kissing_event_derive(input: TokenStream) -> TokenStream {
 	kissing_event_derive::kissing_event_derive_impl(input)
 }
 
+/// Registers a Bevy system as a one-shot system that can be called from GDScript through
+/// the `#[kiss_bevy]` app node.
+///
+/// The system's `In<T>` input is the function's arguments; a tuple is passed as multiple
+/// arguments. Its output is returned to GDScript, so it must implement `ToGodot`.
+/// ```rust
+/// #[kissing_function]
+/// fn get_inventory_count(In(player): In<i64>, inventories: Query<&Inventory>) -> i32 {
+///     // ...
+/// }
+/// ```
+///
+/// It can then be called from GDScript:
+/// ```gdscript
+/// var count = MyAppNodeName.call_kissing_function("get_inventory_count", [player_id])
+/// ```
+///
+/// The system runs synchronously, so it should not be called while Bevy is processing.
+#[proc_macro_attribute]
+pub fn kissing_function(attr: TokenStream, item: TokenStream) -> TokenStream {
+	kissing_function::kissing_function_impl(attr, item)
+}
+
+/// Exposes a Bevy [`bevy::prelude::Resource`] to GDScript through the `#[kiss_bevy]` app node.
+///
+/// Fields with the `#[export]` attribute can be read and written by name, and the app node
+/// emits `bevy_resource_changed` with the resource's name when Bevy detects a change.
+/// ```rust
+/// #[derive(Resource, Default, KissingResource)]
+/// struct Score {
+///     #[export]
+///     points: i32,
+/// }
+/// ```
+#[proc_macro_derive(KissingResource, attributes(export))]
+pub fn kissing_resource_derive(input: TokenStream) -> TokenStream {
+	kissing_resource_derive::kissing_resource_derive_impl(input)
+}
+
+/// Declares a Bevy [`bevy::prelude::Resource`] whose `#[export]` fields are read from Godot's
+/// `ProjectSettings` before the app's entry function runs.
+///
+/// Each field is stored under `<prefix>/<field>`. The prefix defaults to the struct's name in
+/// snake_case. Missing settings are added with the field's `#[initial_value]` or `Default`.
+/// ```rust
+/// #[derive(Resource, KissingSettings)]
+/// #[kissing_settings(prefix = "game/player", reload_on_change)]
+/// struct PlayerSettings {
+///     #[export]
+///     #[initial_value = 5.0]
+///     speed: f32,
+/// }
+/// ```
+///
+/// With `reload_on_change`, the resource is re-read whenever the project settings change.
+#[proc_macro_derive(KissingSettings, attributes(kissing_settings, export, initial_value))]
+pub fn kissing_settings_derive(input: TokenStream) -> TokenStream {
+	kissing_settings_derive::kissing_settings_derive_impl(input)
+}
+
+/// Makes a Bevy component a marker for a Godot group, so the group can be used in query
+/// filters like `With<Enemy>`.
+///
+/// The component must implement `Default`. It's inserted on entities whose node is in the group
+/// when `GodotGroupsPlugin` is added, and kept in sync by the `AddToGroup` and `RemoveFromGroup` events.
+/// ```rust
+/// #[derive(Component, Default, GodotGroupMarker)]
+/// #[godot_group("enemies")]
+/// struct Enemy;
+/// ```
+#[proc_macro_derive(GodotGroupMarker, attributes(godot_group))]
+pub fn godot_group_marker_derive(input: TokenStream) -> TokenStream {
+	godot_group_marker_derive::godot_group_marker_derive_impl(input)
+}
+
 /// This should be added to all custom Godot types added in Rust so they will appear in the hierarchy.
 #[proc_macro_derive(KissingNode)]
 pub fn kissing_node_derive(input: TokenStream) -> TokenStream {
@@ -172,7 +439,7 @@ pub fn kissing_node_derive(input: TokenStream) -> TokenStream {
 /// ```rust
 /// #[plugin_and_kissing_component(Cool)]
 /// pub(crate) fn cool_plugin(app: &mut App) {
-/// 	app.add_systems(Startup, init_cool_stuff);
+///     app.add_systems(Startup, init_cool_stuff);
 /// }
 /// ```
 ///
@@ -184,9 +451,9 @@ pub fn kissing_node_derive(input: TokenStream) -> TokenStream {
 ///
 /// pub(crate) struct CoolPlugin;
 /// impl Plugin for CoolPlugin {
-/// 	fn build(&self, app: &mut App) {
-/// 		app.add_systems(Startup, init_cool_stuff);
-/// 	}
+///     fn build(&self, app: &mut App) {
+///         app.add_systems(Startup, init_cool_stuff);
+///     }
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -5,6 +5,23 @@
 pub(crate) struct IDTypeInfo {
 	pub(crate) kind: NodeOrResource,
 	pub(crate) is_array: bool,
+
+	/// The `T` of `GodotNodeRef<T>` or `GodotResourceRef<T>`, or `None` for the untyped ids.
+	pub(crate) class: Option<syn::Type>,
+}
+
+/// The arguments of `#[export(...)]` that are handled by Bevy💋Godot instead of gdext.
+#[derive(Default)]
+pub(crate) struct KissingExportArguments {
+	/// Previous names of the field from `rename_from = "old"`.
+	pub(crate) renamed_from: Vec<syn::LitStr>,
+
+	/// The `KissingComponentFieldHint` for gdext's `range`, `enum`, `flags`, `file`, `dir`,
+	/// `multiline` and `color_no_alpha` arguments. These are still passed to gdext.
+	pub(crate) hint: Option<proc_macro2::TokenStream>,
+
+	/// The `enum` or `flags` argument, whose values are only constrained on integer fields.
+	pub(crate) enum_or_flags: Option<proc_macro2::Ident>,
 }
 
 // ---------
@@ -29,24 +46,54 @@ pub(crate) fn generate_godot_object_name_for_kissing_component_data(
 	format!("{}_KissingDataObject", original_ident)
 }
 
+/// Returns how the field at `index` is accessed in code and its name in the editor fields.
+///
+/// Tuple fields use the naming of `tuple_component_field_name` in `bevy_kissing_godot`:
+/// `"value"` for the only field, otherwise `"_0"`, `"_1"`, etc.
+pub(crate) fn get_field_member_and_name(
+	index: usize,
+	field: &syn::Field,
+	field_count: usize,
+) -> (syn::Member, String) {
+	match &field.ident {
+		Some(ident) => (syn::Member::Named(ident.clone()), ident.to_string()),
+		None => (
+			syn::Member::Unnamed(syn::Index::from(index)),
+			if field_count == 1 {
+				"value".to_string()
+			} else {
+				format!("_{}", index)
+			},
+		),
+	}
+}
+
 pub(crate) fn is_field_export(field: &syn::Field) -> bool {
 	field.attrs.iter().any(|a| match a.path().get_ident() {
 		Some(ident) => matches!(
 			ident.to_string().as_str(),
-			"export" | "export_node" | "export_resource" | "export_string"
+			"export" | "export_entity" | "export_node" | "export_resource" | "export_string"
 		),
 		_ => false,
 	})
 }
 
-pub(crate) fn is_field_export_string(field: &syn::Field) -> bool {
-	field.attrs.iter().any(|a| match a.path().get_ident() {
-		Some(ident) => ident.to_string().as_str() == "export_string",
-		_ => false,
-	})
+pub(crate) fn is_field_export_entity(field: &syn::Field) -> bool {
+	field
+		.attrs
+		.iter()
+		.any(|a| a.path().is_ident("export_entity"))
+}
+
+pub(crate) fn is_field_export_group(field: &syn::Field) -> bool {
+	field
+		.attrs
+		.iter()
+		.any(|a| a.path().is_ident("export_group"))
 }
 
-/// Checks if the type is `Option<X>` or `Vec<X>` with `X` either being `GodotNodeId` or `GodotResourceId`.
+/// Checks if the type is `Option<X>` or `Vec<X>` with `X` being `GodotNodeId`, `GodotResourceId`,
+/// `GodotNodeRef<T>` or `GodotResourceRef<T>`.
 ///
 /// This implementation is flawed as it only checks the final identifier for both types,
 /// so `something::Option<whatever::GodotNodeId>` will return `true`.
@@ -56,9 +103,7 @@ pub(crate) fn is_node_or_resource_id(ty: &syn::Type) -> Option<IDTypeInfo> {
 	let Type::Path(type_path) = ty else {
 		return None;
 	};
-	let Some(segment) = type_path.path.segments.last() else {
-		return None;
-	};
+	let segment = type_path.path.segments.last()?;
 
 	let is_array = segment.ident == "Vec";
 	if !is_array && segment.ident != "Option" {
@@ -77,37 +122,344 @@ pub(crate) fn is_node_or_resource_id(ty: &syn::Type) -> Option<IDTypeInfo> {
 	let Type::Path(inner_path) = inner_ty else {
 		return None;
 	};
-	let Some(path_segment) = inner_path.path.segments.last() else {
-		return None;
-	};
+	let path_segment = inner_path.path.segments.last()?;
 
-	let kind = if path_segment.ident == "GodotNodeId" {
+	let kind = if path_segment.ident == "GodotNodeId" || path_segment.ident == "GodotNodeRef" {
 		NodeOrResource::Node
-	} else if path_segment.ident == "GodotResourceId" {
+	} else if path_segment.ident == "GodotResourceId" || path_segment.ident == "GodotResourceRef" {
 		NodeOrResource::Resource
 	} else {
 		return None;
 	};
 
-	Some(IDTypeInfo { kind, is_array })
+	// Get the `T` of `GodotNodeRef<T>` and `GodotResourceRef<T>`.
+	let class = match &path_segment.arguments {
+		PathArguments::AngleBracketed(ref_type_args) if ref_type_args.args.len() == 1 => {
+			match &ref_type_args.args[0] {
+				GenericArgument::Type(class) => Some(class.clone()),
+				_ => return None,
+			}
+		}
+		PathArguments::None if !path_segment.ident.to_string().ends_with("Ref") => None,
+		_ => return None,
+	};
+
+	Some(IDTypeInfo {
+		kind,
+		is_array,
+		class,
+	})
+}
+
+/// Returns the expression of an `#[initial_value = EXPR]` or `#[initial_value(EXPR)]` attribute
+/// in the list, or `Default::default()` for `#[initial_value]`.
+///
+/// Returns `None` if there is no `#[initial_value]` attribute.
+pub(crate) fn get_initial_value_from_attrs(
+	attrs: &[syn::Attribute],
+) -> Option<proc_macro2::TokenStream> {
+	use quote::{ToTokens, quote};
+	use syn::Meta;
+
+	let attr = attrs.iter().find(|a| a.path().is_ident("initial_value"))?;
+	Some(match &attr.meta {
+		Meta::Path(_) => quote!(Default::default()),
+		Meta::List(meta_list) => meta_list.tokens.clone(),
+		Meta::NameValue(meta_name_value) => meta_name_value.value.to_token_stream(),
+	})
+}
+
+/// Splits the arguments of an `#[export(...)]` attribute into the ones handled by Bevy💋Godot
+/// and a copy of the attribute with only the remaining arguments, which can be passed to gdext.
+pub(crate) fn take_kissing_export_arguments(
+	attr: &syn::Attribute,
+) -> syn::Result<(KissingExportArguments, syn::Attribute)> {
+	use proc_macro2::{TokenStream, TokenTree};
+
+	let mut result = KissingExportArguments::default();
+	let syn::Meta::List(meta_list) = &attr.meta else {
+		return Ok((result, attr.clone()));
+	};
+
+	// Split the arguments at top-level commas. Commas inside `(...)` are part of a `Group`.
+	let mut arguments = vec![vec![]];
+	for token in meta_list.tokens.clone() {
+		match &token {
+			TokenTree::Punct(p) if p.as_char() == ',' => arguments.push(vec![]),
+			_ => arguments.last_mut().unwrap().push(token),
+		}
+	}
+
+	let mut remaining = TokenStream::new();
+	for argument in arguments {
+		if let [TokenTree::Ident(name), TokenTree::Punct(eq), value @ ..] = argument.as_slice()
+			&& eq.as_char() == '='
+			&& name == "rename_from"
+		{
+			let value = value.iter().cloned().collect::<TokenStream>();
+			result.renamed_from.push(syn::parse2(value)?);
+			continue;
+		}
+		if argument.is_empty() {
+			continue;
+		}
+		if let Some(hint) = parse_export_hint(&argument)? {
+			result.hint = Some(hint);
+		}
+		if let Some(TokenTree::Ident(name)) = argument.first()
+			&& (name == "enum" || name == "flags")
+		{
+			result.enum_or_flags = Some(name.clone());
+		}
+		if !remaining.is_empty() {
+			remaining.extend(quote::quote!(,));
+		}
+		remaining.extend(argument);
+	}
+
+	let mut attr = attr.clone();
+	attr.meta = if remaining.is_empty() {
+		syn::Meta::Path(meta_list.path.clone())
+	} else {
+		syn::Meta::List(syn::MetaList {
+			tokens: remaining,
+			..meta_list.clone()
+		})
+	};
+	Ok((result, attr))
+}
+
+/// Returns the `KissingComponentFieldHint` matching a gdext `#[export(...)]` argument like
+/// `range = (0, 10, 1)`, or `None` if the argument isn't a hint.
+///
+/// Explicit `enum` and `flags` values like `A = 4` are passed as `"A:4"`, the format Godot uses.
+/// If a value isn't an integer literal, the argument has no hint.
+fn parse_export_hint(
+	argument: &[proc_macro2::TokenTree],
+) -> syn::Result<Option<proc_macro2::TokenStream>> {
+	use proc_macro2::{Literal, TokenStream, TokenTree};
+	use quote::quote;
+
+	let hint = quote!(bevy_kissing_godot::kissing_component::kissing_component_field_hint::KissingComponentFieldHint);
+
+	let Some(TokenTree::Ident(name)) = argument.first() else {
+		return Ok(None);
+	};
+
+	// The `(...)` or `"..."` after `name =`, if any.
+	let value = match argument.get(1..) {
+		Some([TokenTree::Punct(eq), value @ ..]) if eq.as_char() == '=' => Some(value),
+		_ => None,
+	};
+
+	// The comma-separated items of a `(...)` value.
+	let items = || -> Vec<TokenStream> {
+		let Some([TokenTree::Group(group)]) = value else {
+			return vec![];
+		};
+		let mut items = vec![TokenStream::new()];
+		for token in group.stream() {
+			match &token {
+				TokenTree::Punct(p) if p.as_char() == ',' => items.push(TokenStream::new()),
+				_ => items.last_mut().unwrap().extend([token]),
+			}
+		}
+		items.retain(|item| !item.is_empty());
+		items
+	};
+
+	// Names of `enum = (A, B = 4)` or `flags = (A, B = 4)` like `"B:4"`, or `None` if any
+	// value isn't an integer literal.
+	let names = || -> Option<Vec<String>> {
+		items()
+			.into_iter()
+			.map(|item| match syn::parse2::<syn::Expr>(item).ok()? {
+				syn::Expr::Path(path) => Some(path.path.get_ident()?.to_string()),
+				syn::Expr::Assign(assign) => {
+					let syn::Expr::Path(path) = assign.left.as_ref() else {
+						return None;
+					};
+					let name = path.path.get_ident()?;
+					let value = expr_to_f64(&assign.right).filter(|v| v.fract() == 0.0)?;
+					Some(format!("{}:{}", name, value as i64))
+				}
+				_ => None,
+			})
+			.collect()
+	};
+
+	let f64_tokens = |value: f64| {
+		if value == f64::INFINITY {
+			quote!(f64::INFINITY)
+		} else if value == f64::NEG_INFINITY {
+			quote!(f64::NEG_INFINITY)
+		} else {
+			let value = Literal::f64_unsuffixed(value);
+			quote!(#value)
+		}
+	};
+
+	Ok(Some(match name.to_string().as_str() {
+		"range" => {
+			let mut numbers = vec![];
+			let mut or_greater = false;
+			let mut or_less = false;
+			for item in items() {
+				let expr = syn::parse2::<syn::Expr>(item)?;
+				if let Some(number) = expr_to_f64(&expr) {
+					numbers.push(number);
+				} else if let syn::Expr::Path(path) = &expr {
+					or_greater |= path.path.is_ident("or_greater");
+					or_less |= path.path.is_ident("or_less");
+				}
+			}
+			let (Some(min), Some(max)) = (numbers.first(), numbers.get(1)) else {
+				return Err(syn::Error::new(
+					name.span(),
+					"range requires a minimum and maximum",
+				));
+			};
+			// `constrain_range` clamps to `min..=max`, which panics if they're swapped.
+			if min > max {
+				return Err(syn::Error::new(
+					name.span(),
+					format!(
+						"range minimum ({}) must not be greater than its maximum ({})",
+						min, max
+					),
+				));
+			}
+			let min = f64_tokens(if or_less { f64::NEG_INFINITY } else { *min });
+			let max = f64_tokens(if or_greater { f64::INFINITY } else { *max });
+			let step = f64_tokens(numbers.get(2).copied().unwrap_or(0.0));
+			quote!(#hint::Range { min: #min, max: #max, step: #step })
+		}
+		"enum" | "flags" => {
+			let Some(names) = names() else {
+				return Ok(None);
+			};
+			if name == "enum" {
+				quote!(#hint::Enum(&[#(#names),*]))
+			} else {
+				quote!(#hint::Flags(&[#(#names),*]))
+			}
+		}
+		"file" | "global_file" => {
+			let filters = match value {
+				Some(value) => syn::parse2::<syn::LitStr>(value.iter().cloned().collect())?
+					.value()
+					.split(',')
+					.map(|filter| filter.trim().to_string())
+					.filter(|filter| !filter.is_empty())
+					.collect(),
+				None => vec![],
+			};
+			quote!(#hint::File(&[#(#filters),*]))
+		}
+		"dir" | "global_dir" => quote!(#hint::Dir),
+		"multiline" => quote!(#hint::Multiline),
+		"color_no_alpha" => quote!(#hint::ColorNoAlpha),
+		_ => return Ok(None),
+	}))
+}
+
+/// Returns an error if the `enum` or `flags` argument of `arguments` is used on a field of type
+/// `ty` that can't hold their integer values, such as `f32` (or a string for `flags`).
+pub(crate) fn check_enum_or_flags_field_type(
+	arguments: &KissingExportArguments,
+	ty: &syn::Type,
+) -> syn::Result<()> {
+	let Some(name) = &arguments.enum_or_flags else {
+		return Ok(());
+	};
+	let syn::Type::Path(type_path) = ty else {
+		return Ok(());
+	};
+	let Some(segment) = type_path.path.segments.last() else {
+		return Ok(());
+	};
+	let type_name = segment.ident.to_string();
+	let is_string = matches!(type_name.as_str(), "String" | "GString" | "StringName");
+	if matches!(type_name.as_str(), "f32" | "f64" | "bool") || (is_string && name == "flags") {
+		return Err(syn::Error::new(
+			name.span(),
+			format!("`{}` can't be used on `{}` fields", name, type_name),
+		));
+	}
+	Ok(())
+}
+
+/// Returns the value of a number literal expression like `10`, `0.5` or `-3`.
+fn expr_to_f64(expr: &syn::Expr) -> Option<f64> {
+	match expr {
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Int(int),
+			..
+		}) => int.base10_parse::<f64>().ok(),
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Float(float),
+			..
+		}) => float.base10_parse::<f64>().ok(),
+		syn::Expr::Unary(syn::ExprUnary {
+			op: syn::UnOp::Neg(_),
+			expr,
+			..
+		}) => expr_to_f64(expr).map(|v| -v),
+		_ => None,
+	}
+}
+
+/// Returns an expression for the `KissingComponentFieldHint` of an `#[export]` field: the hint
+/// from its `#[export(...)]` arguments, or the editor hint of its type.
+pub(crate) fn generate_field_editor_hint(field: &syn::Field) -> proc_macro2::TokenStream {
+	let ty = &field.ty;
+	get_kissing_export_arguments(field).hint.unwrap_or_else(
+		|| quote::quote!(<#ty as bevy_kissing_godot::prelude::KissingComponentField>::editor_hint()),
+	)
+}
+
+/// Returns the Bevy💋Godot arguments of the `#[export(...)]` attribute on `field`.
+///
+/// Malformed arguments are ignored here, since they're reported when generating the Godot object.
+pub(crate) fn get_kissing_export_arguments(field: &syn::Field) -> KissingExportArguments {
+	field
+		.attrs
+		.iter()
+		.find(|a| a.path().is_ident("export"))
+		.and_then(|a| take_kissing_export_arguments(a).ok())
+		.map(|(arguments, _)| arguments)
+		.unwrap_or_default()
+}
+
+/// Returns an expression that creates the initial value of `field` from its `#[initial_value]`
+/// attribute, converted with `Into` to the field's type, or `Default::default()` if it has none.
+pub(crate) fn generate_field_initial_value(field: &syn::Field) -> proc_macro2::TokenStream {
+	use quote::quote;
+
+	let ty = &field.ty;
+	match get_initial_value_from_attrs(&field.attrs) {
+		Some(initial_value) => quote! {{
+			let value: #ty = Into::into(#initial_value);
+			value
+		}},
+		None => quote!(<#ty as Default>::default()),
+	}
 }
 
 /// Returns a `String` that's a combination of all `doc` attributes in the list.
-pub(crate) fn get_doc_comment_from_attrs(attrs: &Vec<syn::Attribute>) -> String {
+pub(crate) fn get_doc_comment_from_attrs(attrs: &[syn::Attribute]) -> String {
 	use syn::{Expr, Lit, Meta, MetaNameValue};
 
 	attrs
 		.iter()
 		.filter_map(|attr| {
 			// Only keep attributes that are `doc = "..."`
-			if let Meta::NameValue(MetaNameValue { path, value, .. }) = &attr.meta {
-				if path.is_ident("doc") {
-					if let Expr::Lit(lit) = &value {
-						if let Lit::Str(s) = &lit.lit {
-							return Some(s.value());
-						}
-					}
-				}
+			if let Meta::NameValue(MetaNameValue { path, value, .. }) = &attr.meta
+				&& path.is_ident("doc")
+				&& let Expr::Lit(lit) = &value
+				&& let Lit::Str(s) = &lit.lit
+			{
+				return Some(s.value());
 			}
 			None
 		})